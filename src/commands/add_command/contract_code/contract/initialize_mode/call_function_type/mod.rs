@@ -15,6 +15,10 @@ pub struct CallFunctionAction {
 }
 
 impl CallFunctionAction {
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
     fn input_method_name(
         _context: &crate::common::SignerContext,
     ) -> color_eyre::eyre::Result<String> {
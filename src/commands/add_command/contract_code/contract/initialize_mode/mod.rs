@@ -15,6 +15,16 @@ pub enum NextAction {
 }
 
 impl NextAction {
+    /// The init method called right after the deployment, if any
+    pub fn init_method_name(&self) -> Option<&str> {
+        match self {
+            NextAction::Initialize(call_function_action) => {
+                Some(call_function_action.method_name())
+            }
+            NextAction::NoInitialize(_) => None,
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -4,12 +4,46 @@ mod initialize_mode;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct ContractFile {
     pub file_path: crate::types::path_buf::PathBuf,
+    ///Skip the checks against the protocol limits and the deployed code (true or false)
+    #[interactive_clap(long)]
+    skip_checks: Option<bool>,
     #[interactive_clap(subcommand)]
     next_action: self::initialize_mode::NextAction,
 }
 
+impl ContractFile {
+    pub fn from_cli(
+        optional_clap_variant: Option<<ContractFile as interactive_clap::ToCli>::CliVariant>,
+        context: crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let file_path = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.file_path)
+        {
+            Some(file_path) => file_path,
+            None => Self::input_file_path(&context)?,
+        };
+        let skip_checks = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.skip_checks);
+        let next_action =
+            match optional_clap_variant.and_then(|clap_variant| clap_variant.next_action) {
+                Some(cli_next_action) => {
+                    self::initialize_mode::NextAction::from_cli(Some(cli_next_action), context)?
+                }
+                None => self::initialize_mode::NextAction::choose_variant(context)?,
+            };
+        Ok(Self {
+            file_path,
+            skip_checks,
+            next_action,
+        })
+    }
+}
+
 impl ContractFile {
     fn input_file_path(
         _context: &crate::common::SignerContext,
@@ -33,6 +67,31 @@ impl ContractFile {
                 err
             ))
         })?;
+        let wasm_info = crate::common::inspect_wasm(&code)?;
+        if let Some(init_method_name) = self.next_action.init_method_name() {
+            if !wasm_info
+                .exported_functions
+                .iter()
+                .any(|function| function == init_method_name)
+            {
+                return Err(color_eyre::Report::msg(format!(
+                    "The contract does not export the init method <{}>",
+                    init_method_name
+                )));
+            }
+        }
+        if let Some(connection_config) = &network_connection_config {
+            if !self.skip_checks.unwrap_or(false)
+                && !crate::common::check_code_before_deploy(
+                    connection_config,
+                    prepopulated_unsigned_transaction.receiver_id.clone(),
+                    &code,
+                )
+                .await?
+            {
+                return Ok(());
+            }
+        }
         let action = near_primitives::transaction::Action::DeployContract(
             near_primitives::transaction::DeployContractAction { code },
         );
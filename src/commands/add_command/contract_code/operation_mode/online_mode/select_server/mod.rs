@@ -17,6 +17,9 @@ pub enum SelectServer {
     /// Provide data for the server https://rpc.betanet.near.org
     #[strum_discriminants(strum(message = "Betanet"))]
     Betanet(self::server::Server),
+    /// Provide data for the local sandbox node http://127.0.0.1:3030
+    #[strum_discriminants(strum(message = "Sandbox"))]
+    Sandbox(self::server::Server),
     /// Provide data for a manually specified server
     #[strum_discriminants(strum(message = "Custom"))]
     Custom(self::server::CustomServer),
@@ -44,6 +47,7 @@ impl From<SelectServerContext> for super::super::AddContractCodeCommandNetworkCo
             SelectServerDiscriminants::Testnet => crate::common::ConnectionConfig::Testnet,
             SelectServerDiscriminants::Mainnet => crate::common::ConnectionConfig::Mainnet,
             SelectServerDiscriminants::Betanet => crate::common::ConnectionConfig::Betanet,
+            SelectServerDiscriminants::Sandbox => crate::common::ConnectionConfig::Sandbox,
             SelectServerDiscriminants::Custom => {
                 unreachable!("Network context should not be constructed from Custom variant")
             }
@@ -78,6 +82,12 @@ impl SelectServer {
                     .process(prepopulated_unsigned_transaction, connection_config)
                     .await?;
             }
+            SelectServer::Sandbox(server) => {
+                let connection_config = crate::common::ConnectionConfig::Sandbox;
+                server
+                    .process(prepopulated_unsigned_transaction, connection_config)
+                    .await?;
+            }
             SelectServer::Custom(custom_server) => {
                 custom_server
                     .process(prepopulated_unsigned_transaction)
@@ -1,5 +1,4 @@
 use std::io::Write;
-use std::str::FromStr;
 
 fn bip32path_to_string(bip32path: &slip10::BIP32Path) -> String {
     const HARDEND: u32 = 1 << 31;
@@ -24,59 +23,55 @@ fn bip32path_to_string(bip32path: &slip10::BIP32Path) -> String {
 /// Ed25519 keys)
 #[derive(Debug, Clone, Default, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = ())]
-pub struct CliGenerateKeypair {}
+#[interactive_clap(skip_default_from_cli)]
+pub struct CliGenerateKeypair {
+    ///The seed phrase to derive the key pair from; a new one is generated if it is missing
+    #[interactive_clap(long)]
+    pub master_seed_phrase: Option<String>,
+    ///The HD path of the key pair (m/44'/397'/0' by default)
+    #[interactive_clap(long)]
+    pub seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
+    ///The optional BIP39 passphrase; it is not stored, keep it to recover the key pair
+    #[interactive_clap(long)]
+    pub passphrase: Option<String>,
+}
 
 impl CliGenerateKeypair {
-    pub async fn process(self) -> crate::CliResult {
-        let new_master_seed_phrase_words_count: usize = 12;
-        let seed_phrase_hd_path = slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
+    pub fn from_cli(
+        optional_clap_variant: Option<<CliGenerateKeypair as interactive_clap::ToCli>::CliVariant>,
+        _context: (),
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(match optional_clap_variant {
+            Some(clap_variant) => Self {
+                master_seed_phrase: clap_variant.master_seed_phrase,
+                seed_phrase_hd_path: clap_variant.seed_phrase_hd_path,
+                passphrase: clap_variant.passphrase,
+            },
+            None => Self::default(),
+        })
+    }
 
-        let (master_seed_phrase, master_seed) = {
-            let mnemonic = bip39::Mnemonic::generate(new_master_seed_phrase_words_count)?;
-            let mut master_seed_phrase = String::new();
-            for (index, word) in mnemonic.word_iter().enumerate() {
-                if index != 0 {
-                    master_seed_phrase.push(' ');
-                }
-                master_seed_phrase.push_str(word);
-            }
-            (master_seed_phrase, mnemonic.to_seed(""))
-        };
-        let derived_private_key = slip10::derive_key_from_path(
-            &master_seed,
-            slip10::Curve::Ed25519,
-            &seed_phrase_hd_path,
+    pub async fn process(self) -> crate::CliResult {
+        let key_pair_properties = crate::common::generate_keypair(
+            &crate::common::KeyDerivationParams::from_seed_phrase_options(
+                self.master_seed_phrase,
+                self.seed_phrase_hd_path,
+                self.passphrase,
+            ),
         )
-        .map_err(|err| {
-            color_eyre::Report::msg(format!(
-                "Failed to derive a key from the master key: {}",
-                err
-            ))
-        })?;
-
-        let secret_keypair = {
-            let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key)?;
-            let public = ed25519_dalek::PublicKey::from(&secret);
-            ed25519_dalek::Keypair { secret, public }
-        };
-        let implicit_account_id = hex::encode(&secret_keypair.public);
-        let public_key_str = format!(
-            "ed25519:{}",
-            bs58::encode(&secret_keypair.public).into_string()
-        );
-        let secret_keypair_str = format!(
-            "ed25519:{}",
-            bs58::encode(&secret_keypair.to_bytes()).into_string()
-        );
+        .await?;
+        let implicit_account_id = key_pair_properties
+            .implicit_account_id
+            .expect("ED25519 key pairs always have an implicit account");
 
         let buf = format!(
             "{}",
             serde_json::json!({
-            "master_seed_phrase": master_seed_phrase,
-            "seed_phrase_hd_path": bip32path_to_string(&seed_phrase_hd_path),
+            "master_seed_phrase": key_pair_properties.master_seed_phrase,
+            "seed_phrase_hd_path": bip32path_to_string(&key_pair_properties.seed_phrase_hd_path),
             "account_id": implicit_account_id,
-            "public_key": public_key_str,
-            "private_key": secret_keypair_str,
+            "public_key": key_pair_properties.public_key_str,
+            "private_key": key_pair_properties.secret_keypair_str,
             })
         );
         let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
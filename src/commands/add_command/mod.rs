@@ -5,6 +5,7 @@ mod contract_code;
 mod implicit_account;
 mod stake_proposal;
 mod sub_account;
+mod top_level_account;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = ())]
@@ -42,6 +43,11 @@ pub enum Action {
     #[strum_discriminants(strum(message = "Add a new sub-account"))]
     ///Add a new sub-account
     SubAccount(self::sub_account::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Add a new top-level account (e.g. alice.testnet) through the registrar"
+    ))]
+    ///Add a new top-level account through the registrar contract of the network
+    TopLevelAccount(self::top_level_account::operation_mode::OperationMode),
 }
 
 impl Action {
@@ -71,6 +77,11 @@ impl Action {
                     .process(prepopulated_unsigned_transaction)
                     .await
             }
+            Action::TopLevelAccount(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
         }
     }
 }
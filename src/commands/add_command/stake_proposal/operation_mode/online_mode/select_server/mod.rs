@@ -17,6 +17,9 @@ pub enum SelectServer {
     #[strum_discriminants(strum(message = "Betanet"))]
     /// providing data to server https://rpc.betanet.near.org
     Betanet(self::server::Server),
+    #[strum_discriminants(strum(message = "Sandbox"))]
+    /// providing data to the local sandbox node http://127.0.0.1:3030
+    Sandbox(self::server::Server),
     #[strum_discriminants(strum(message = "Custom"))]
     /// providing data to the manually specified server
     Custom(self::server::CustomServer),
@@ -43,6 +46,7 @@ impl From<SelectServerContext> for super::super::AddStakeProposalCommandNetworkC
             SelectServerDiscriminants::Testnet => crate::common::ConnectionConfig::Testnet,
             SelectServerDiscriminants::Mainnet => crate::common::ConnectionConfig::Mainnet,
             SelectServerDiscriminants::Betanet => crate::common::ConnectionConfig::Betanet,
+            SelectServerDiscriminants::Sandbox => crate::common::ConnectionConfig::Sandbox,
             SelectServerDiscriminants::Custom => {
                 unreachable!("Network context should not be constructed from Custom variant")
             }
@@ -77,6 +81,12 @@ impl SelectServer {
                     .process(prepopulated_unsigned_transaction, connection_config)
                     .await?;
             }
+            SelectServer::Sandbox(server) => {
+                let connection_config = crate::common::ConnectionConfig::Sandbox;
+                server
+                    .process(prepopulated_unsigned_transaction, connection_config)
+                    .await?;
+            }
             SelectServer::Custom(custom_server) => {
                 custom_server
                     .process(prepopulated_unsigned_transaction)
@@ -0,0 +1,51 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext)]
+pub struct CreateAccountDeposit {
+    pub amount: crate::common::NearBalance,
+    #[interactive_clap(named_arg)]
+    ///Specify a signer
+    pub signer: super::sender::Sender,
+}
+
+impl CreateAccountDeposit {
+    fn input_amount(
+        _context: &super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
+            .with_prompt("How many NEAR Tokens do you want to deposit to the new account? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .with_initial_text("0.1 NEAR")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        new_account_id: near_primitives::types::AccountId,
+        public_key: near_crypto::PublicKey,
+    ) -> crate::CliResult {
+        let args = serde_json::json!({
+            "new_account_id": new_account_id.to_string(),
+            "new_public_key": public_key.to_string(),
+        });
+        let action = near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: "create_account".to_string(),
+                args: args.to_string().into_bytes(),
+                gas: super::CREATE_ACCOUNT_GAS,
+                deposit: self.amount.to_yoctonear(),
+            },
+        );
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(action);
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        self.signer
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,8 @@
+mod deposit;
+mod new_account;
+pub mod operation_mode;
+mod public_key_mode;
+mod sender;
+
+/// Gas attached to the `create_account` call of the registrar contract (30 TeraGas)
+const CREATE_ACCOUNT_GAS: u64 = 30000000000000;
@@ -0,0 +1,110 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext)]
+pub struct NewAccount {
+    #[interactive_clap(skip_default_from_cli)]
+    pub new_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    pub registrar_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub public_key_mode: super::public_key_mode::PublicKeyMode,
+}
+
+impl NewAccount {
+    fn from_cli_new_account_id(
+        optional_cli_new_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_new_account_id {
+            Some(cli_new_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_new_account_id.clone().into(),
+            )? {
+                Some(_) => {
+                    println!("Account <{}> is already taken", cli_new_account_id);
+                    Self::input_new_account_id(&context)
+                }
+                None => Ok(cli_new_account_id),
+            },
+            None => Self::input_new_account_id(&context),
+        }
+    }
+
+    fn input_new_account_id(
+        context: &super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What is the new account ID? (e.g. alice.testnet)")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                println!(
+                    "Account <{}> is already taken, please choose another name",
+                    account_id
+                );
+            } else {
+                break Ok(account_id);
+            }
+        }
+    }
+
+    fn input_registrar_account_id(
+        context: &super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        let mut input = Input::new();
+        input.with_prompt("What is the account ID of the registrar contract?");
+        if let Some(linkdrop_account_id) = context.connection_config.linkdrop_account_id() {
+            input.with_initial_text(linkdrop_account_id.to_string());
+        }
+        Ok(input.interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let new_account_id: near_primitives::types::AccountId = self.new_account_id.into();
+        let registrar_account_id: near_primitives::types::AccountId =
+            self.registrar_account_id.into();
+        if let Some((_, parent_account_id)) = new_account_id.as_ref().split_once('.') {
+            if parent_account_id != registrar_account_id.as_ref() {
+                return Err(color_eyre::Report::msg(format!(
+                    "Account <{}> cannot be created by the registrar <{}>: only accounts like <name.{}> can be created",
+                    new_account_id, registrar_account_id, registrar_account_id
+                )));
+            }
+        }
+        if let Ok(_) = near_jsonrpc_client::JsonRpcClient::connect(
+            network_connection_config.rpc_url().as_str(),
+        )
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccount {
+                account_id: new_account_id.clone(),
+            },
+        })
+        .await
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "Account <{}> is already taken",
+                new_account_id
+            )));
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: registrar_account_id,
+            ..prepopulated_unsigned_transaction
+        };
+        self.public_key_mode
+            .process(
+                unsigned_transaction,
+                network_connection_config,
+                new_account_id,
+            )
+            .await
+    }
+}
@@ -0,0 +1,153 @@
+use dialoguer::Input;
+use std::str::FromStr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext)]
+///Add a full access key for the new account
+pub enum PublicKeyMode {
+    #[strum_discriminants(strum(message = "Enter public key"))]
+    /// Enter public key
+    PublicKey(AddPublicKey),
+    #[strum_discriminants(strum(message = "Generate key pair"))]
+    /// Generate key pair and save it to the keychain
+    GenerateKeypair(GenerateKeypair),
+}
+
+impl PublicKeyMode {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        new_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        match self {
+            PublicKeyMode::PublicKey(add_public_key) => {
+                add_public_key
+                    .deposit
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        new_account_id,
+                        add_public_key.public_key.into(),
+                    )
+                    .await
+            }
+            PublicKeyMode::GenerateKeypair(generate_keypair) => {
+                generate_keypair
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        new_account_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext)]
+pub struct AddPublicKey {
+    pub public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(named_arg)]
+    ///Enter an amount
+    pub deposit: super::deposit::CreateAccountDeposit,
+}
+
+impl AddPublicKey {
+    fn input_public_key(
+        _context: &super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
+        Ok(Input::new()
+            .with_prompt("Enter a public key for the new account")
+            .interact_text()?)
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct GenerateKeypair {
+    ///The seed phrase to derive the key pair from; a new one is generated if it is missing
+    #[interactive_clap(long)]
+    pub master_seed_phrase: Option<String>,
+    ///The HD path of the key pair (m/44'/397'/0' by default)
+    #[interactive_clap(long)]
+    pub seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
+    ///The optional BIP39 passphrase; it is not stored, keep it to recover the key pair
+    #[interactive_clap(long)]
+    pub passphrase: Option<String>,
+    #[interactive_clap(named_arg)]
+    ///Enter an amount
+    pub deposit: super::deposit::CreateAccountDeposit,
+}
+
+impl GenerateKeypair {
+    pub fn from_cli(
+        optional_clap_variant: Option<<GenerateKeypair as interactive_clap::ToCli>::CliVariant>,
+        context: super::operation_mode::online_mode::select_server::AddTopLevelAccountCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let master_seed_phrase = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.master_seed_phrase);
+        let seed_phrase_hd_path = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.seed_phrase_hd_path);
+        let passphrase = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.passphrase);
+        let deposit = super::deposit::CreateAccountDeposit::from_cli(
+            optional_clap_variant.and_then(|clap_variant| match clap_variant.deposit {
+                Some(ClapNamedArgCreateAccountDepositForGenerateKeypair::Deposit(cli_args)) => {
+                    Some(cli_args)
+                }
+                None => None,
+            }),
+            context,
+        )?;
+        Ok(Self {
+            master_seed_phrase,
+            seed_phrase_hd_path,
+            passphrase,
+            deposit,
+        })
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        new_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let key_pair_properties: crate::common::KeyPairProperties =
+            crate::common::generate_keypair(
+                &crate::common::KeyDerivationParams::from_seed_phrase_options(
+                    self.master_seed_phrase.clone(),
+                    self.seed_phrase_hd_path.clone(),
+                    self.passphrase.clone(),
+                ),
+            )
+            .await?;
+        crate::common::save_access_key_to_keychain(
+            Some(network_connection_config.clone()),
+            key_pair_properties.clone(),
+            &new_account_id.to_string(),
+            near_primitives::views::AccessKeyPermissionView::FullAccess,
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })?;
+        let public_key = near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
+        self.deposit
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                new_account_id,
+                public_key,
+            )
+            .await
+    }
+}
@@ -0,0 +1,117 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod prepare;
+mod sign;
+mod submit;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct BundleCommand {
+    #[interactive_clap(subcommand)]
+    pub action: BundleAction,
+}
+
+impl BundleCommand {
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///What do you want to do with the transaction bundle?
+pub enum BundleAction {
+    #[strum_discriminants(strum(
+        message = "Prepare (online): fill in the nonce and a recent block hash and write a bundle file"
+    ))]
+    ///Fill in the nonce and a recent block hash of an unsigned transaction and write a bundle file
+    Prepare(self::prepare::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Sign (offline): sign the transaction of a bundle file and write a signed bundle file"
+    ))]
+    ///Sign the transaction of a bundle file and write a signed bundle file
+    Sign(self::sign::Sign),
+    #[strum_discriminants(strum(
+        message = "Submit (online): check that the signed bundle is still valid and send it"
+    ))]
+    ///Check that the block hash of the signed bundle is still valid and send the transaction
+    Submit(self::submit::Submit),
+}
+
+impl BundleAction {
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::Prepare(operation_mode) => operation_mode.process().await,
+            Self::Sign(sign) => sign.process().await,
+            Self::Submit(submit) => submit.process().await,
+        }
+    }
+}
+
+/// Everything an air-gapped machine needs to sign a transaction, and the signed transaction
+/// once it is signed
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct TransactionBundle {
+    network: String,
+    signer_id: near_primitives::types::AccountId,
+    signer_public_key: near_crypto::PublicKey,
+    nonce: near_primitives::types::Nonce,
+    block_hash: near_primitives::hash::CryptoHash,
+    block_height: near_primitives::types::BlockHeight,
+    block_time: String,
+    /// Base64-encoded borsh serialization of the unsigned transaction
+    unsigned_transaction: String,
+    /// Base64-encoded borsh serialization of the signed transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signed_transaction: Option<String>,
+}
+
+impl TransactionBundle {
+    fn read(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(file_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to open or read the bundle file {}: {}",
+                file_path.display(),
+                err
+            ))
+        })?;
+        serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to parse the bundle file: {}", err))
+        })
+    }
+
+    fn write(&self, file_path: &std::path::Path) -> crate::CliResult {
+        std::fs::write(file_path, serde_json::to_string_pretty(self)?).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to write the bundle file {}: {}",
+                file_path.display(),
+                err
+            ))
+        })?;
+        println!("The bundle is saved in a file {}", file_path.display());
+        Ok(())
+    }
+
+    fn unsigned_transaction(
+        &self,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
+        let unsigned_transaction: crate::common::TransactionAsBase64 = self
+            .unsigned_transaction
+            .parse()
+            .map_err(color_eyre::Report::msg)?;
+        Ok(unsigned_transaction.inner)
+    }
+
+    fn signed_transaction(
+        &self,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+        let signed_transaction: crate::common::SignedTransactionAsBase64 = self
+            .signed_transaction
+            .as_deref()
+            .ok_or_else(|| color_eyre::Report::msg("The bundle is not signed yet"))?
+            .parse()
+            .map_err(color_eyre::Report::msg)?;
+        Ok(signed_transaction.inner)
+    }
+}
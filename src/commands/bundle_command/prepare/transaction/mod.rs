@@ -0,0 +1,95 @@
+use dialoguer::Input;
+use near_primitives::borsh::BorshSerialize;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::BundlePrepareCommandNetworkContext)]
+pub struct PrepareTransaction {
+    ///The base64-encoded unsigned transaction (e.g. from "sign the transaction somewhere else")
+    unsigned_transaction: crate::common::TransactionAsBase64,
+    ///The bundle file to write
+    output_file: crate::types::path_buf::PathBuf,
+}
+
+impl PrepareTransaction {
+    fn input_unsigned_transaction(
+        _context: &super::operation_mode::online_mode::select_server::BundlePrepareCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::common::TransactionAsBase64> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter the base64-encoded unsigned transaction")
+            .interact_text()?)
+    }
+
+    fn input_output_file(
+        _context: &super::operation_mode::online_mode::select_server::BundlePrepareCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        let output_file: String = Input::new()
+            .with_prompt("Where do you want to save the bundle?")
+            .with_initial_text("transaction-bundle.json")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(output_file).into())
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let json_rpc_client = near_jsonrpc_client::JsonRpcClient::connect(
+            network_connection_config.rpc_url().as_str(),
+        );
+        let transaction = self.unsigned_transaction.inner;
+        let access_key_response = json_rpc_client
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: transaction.signer_id.clone(),
+                    public_key: transaction.public_key.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch public key information for nonce: {:?}",
+                    err
+                ))
+            })?;
+        let current_nonce =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key) =
+                access_key_response.kind
+            {
+                access_key.nonce
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+            };
+        let status = json_rpc_client
+            .call(near_jsonrpc_client::methods::status::RpcStatusRequest)
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to fetch the recent block hash: {:?}", err))
+            })?;
+
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            nonce: current_nonce + 1,
+            block_hash: status.sync_info.latest_block_hash,
+            ..transaction
+        };
+        println!("\nUnsigned transaction:");
+        crate::common::print_transaction(unsigned_transaction.clone());
+        let bundle = super::super::TransactionBundle {
+            network: network_connection_config.network_name(),
+            signer_id: unsigned_transaction.signer_id.clone(),
+            signer_public_key: unsigned_transaction.public_key.clone(),
+            nonce: unsigned_transaction.nonce,
+            block_hash: unsigned_transaction.block_hash,
+            block_height: status.sync_info.latest_block_height,
+            block_time: status.sync_info.latest_block_time.to_rfc3339(),
+            unsigned_transaction: near_primitives::serialize::to_base64(
+                unsigned_transaction
+                    .try_to_vec()
+                    .expect("Transaction is not expected to fail on serialization"),
+            ),
+            signed_transaction: None,
+        };
+        bundle.write(&self.output_file.0)
+    }
+}
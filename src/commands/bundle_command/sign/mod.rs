@@ -0,0 +1,84 @@
+use dialoguer::Input;
+use near_primitives::borsh::BorshSerialize;
+
+/// Signing works without any network connection, so it can run on an air-gapped machine
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Sign {
+    ///The bundle file created by "bundle prepare"
+    bundle_file: crate::types::path_buf::PathBuf,
+    ///The private key of the access key the transaction is signed with
+    signer_private_key: crate::types::secret_key::SecretKey,
+    ///The signed bundle file to write
+    output_file: crate::types::path_buf::PathBuf,
+}
+
+impl Sign {
+    fn input_bundle_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let bundle_file: String = Input::new()
+            .with_prompt("What is the location of the bundle file?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(bundle_file).into())
+    }
+
+    fn input_signer_private_key(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::secret_key::SecretKey> {
+        Ok(Input::new()
+            .with_prompt("Enter the private key of the signer")
+            .interact_text()?)
+    }
+
+    fn input_output_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        let output_file: String = Input::new()
+            .with_prompt("Where do you want to save the signed bundle?")
+            .with_initial_text("transaction-bundle-signed.json")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(output_file).into())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut bundle = super::TransactionBundle::read(&self.bundle_file.0)?;
+        let unsigned_transaction = bundle.unsigned_transaction()?;
+        if unsigned_transaction.block_hash != bundle.block_hash
+            || unsigned_transaction.nonce != bundle.nonce
+            || unsigned_transaction.signer_id != bundle.signer_id
+        {
+            return Err(color_eyre::Report::msg(
+                "The transaction does not match the signer, the block hash or the nonce of the bundle",
+            ));
+        }
+        let signer_secret_key: near_crypto::SecretKey = self.signer_private_key.into();
+        if signer_secret_key.public_key() != bundle.signer_public_key
+            || unsigned_transaction.public_key != bundle.signer_public_key
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "The private key does not match the public key <{}> of the bundle",
+                bundle.signer_public_key
+            )));
+        }
+        println!("\nNetwork: {}", bundle.network);
+        println!(
+            "The block hash is taken from block #{} ({}); the transaction expires about a day after it",
+            bundle.block_height, bundle.block_time
+        );
+        println!("\nTransaction to sign:");
+        crate::common::print_transaction(unsigned_transaction.clone());
+
+        let signature = signer_secret_key.sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+        let signed_transaction =
+            near_primitives::transaction::SignedTransaction::new(signature, unsigned_transaction);
+        bundle.signed_transaction = Some(near_primitives::serialize::to_base64(
+            signed_transaction
+                .try_to_vec()
+                .expect("Transaction is not expected to fail on serialization"),
+        ));
+        println!("Your transaction was signed successfully.");
+        bundle.write(&self.output_file.0)
+    }
+}
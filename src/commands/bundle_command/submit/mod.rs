@@ -0,0 +1,123 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Submit {
+    ///The signed bundle file created by "bundle sign"
+    bundle_file: crate::types::path_buf::PathBuf,
+}
+
+impl Submit {
+    fn input_bundle_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let bundle_file: String = Input::new()
+            .with_prompt("What is the location of the signed bundle file?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(bundle_file).into())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let bundle = super::TransactionBundle::read(&self.bundle_file.0)?;
+        let signed_transaction = bundle.signed_transaction()?;
+        if signed_transaction.transaction.block_hash != bundle.block_hash
+            || signed_transaction.transaction.nonce != bundle.nonce
+        {
+            return Err(color_eyre::Report::msg(
+                "The signed transaction does not match the block hash or the nonce of the bundle",
+            ));
+        }
+        crate::common::verify_transaction_signature(&signed_transaction)?;
+        let connection_config =
+            crate::common::ConnectionConfig::from_network_name(&bundle.network)?;
+        check_block_hash_validity(&connection_config, &bundle).await?;
+
+        println!("Transaction sent ...");
+        let json_rpc_client =
+            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str());
+        let transaction_info = loop {
+            let transaction_info_result = json_rpc_client
+                .call(
+                    near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                        signed_transaction: signed_transaction.clone(),
+                    },
+                )
+                .await;
+            match transaction_info_result {
+                Ok(response) => {
+                    break response;
+                }
+                Err(err) => match crate::common::rpc_transaction_error(err) {
+                    Ok(_) => actix::clock::sleep(std::time::Duration::from_millis(100)).await,
+                    Err(report) => return Err(report),
+                },
+            };
+        };
+        crate::common::print_transaction_status(transaction_info, Some(connection_config));
+        Ok(())
+    }
+}
+
+/// A transaction is only accepted while its block hash is at most `transaction_validity_period`
+/// blocks old, so refuse to send an expired bundle instead of getting an opaque RPC error
+async fn check_block_hash_validity(
+    connection_config: &crate::common::ConnectionConfig,
+    bundle: &super::TransactionBundle,
+) -> crate::CliResult {
+    let json_rpc_client =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str());
+    let expired = || {
+        color_eyre::Report::msg(format!(
+            "The block hash of the bundle (block #{}, {}) has expired. Prepare and sign the transaction again.",
+            bundle.block_height, bundle.block_time
+        ))
+    };
+    let block_height = match json_rpc_client
+        .call(near_jsonrpc_client::methods::block::RpcBlockRequest {
+            block_reference: near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Hash(bundle.block_hash),
+            ),
+        })
+        .await
+    {
+        Ok(block) => block.header.height,
+        // The block is unknown or already garbage collected
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::blocks::RpcBlockError::UnknownBlock { .. },
+            ),
+        )) => return Err(expired()),
+        Err(err) => {
+            return Err(color_eyre::Report::msg(format!(
+                "Failed to fetch the block of the bundle: {:?}",
+                err
+            )))
+        }
+    };
+    let protocol_config = json_rpc_client
+        .call(
+            near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+            },
+        )
+        .await
+        .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))?;
+    let status = json_rpc_client
+        .call(near_jsonrpc_client::methods::status::RpcStatusRequest)
+        .await
+        .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))?;
+    let age = status
+        .sync_info
+        .latest_block_height
+        .saturating_sub(block_height);
+    if age > protocol_config.transaction_validity_period {
+        return Err(expired());
+    }
+    println!(
+        "The block hash of the bundle is {} blocks old and is still valid for about {} blocks",
+        age,
+        protocol_config.transaction_validity_period - age
+    );
+    Ok(())
+}
@@ -112,6 +112,10 @@ pub enum Submit {
         message = "I only want to print base64-encoded transaction for JSON RPC input and exit"
     ))]
     Display,
+    #[strum_discriminants(strum(
+        message = "I want to show the transaction as QR codes for another device and exit"
+    ))]
+    DisplayQrCode,
 }
 
 impl Submit {
@@ -135,6 +139,7 @@ impl Submit {
         match variants[select_submit] {
             SubmitDiscriminants::Send => Submit::Send,
             SubmitDiscriminants::Display => Submit::Display,
+            SubmitDiscriminants::DisplayQrCode => Submit::DisplayQrCode,
         }
     }
 
@@ -143,6 +148,9 @@ impl Submit {
         serialize_to_base64: String,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         println!("Serialize_to_base64:\n{}", &serialize_to_base64);
+        if let Submit::DisplayQrCode = self {
+            crate::qr_code::print_qr_codes(&serialize_to_base64)?;
+        }
         Ok(None)
     }
 
@@ -180,6 +188,11 @@ impl Submit {
                 println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
                 Ok(None)
             }
+            Submit::DisplayQrCode => {
+                println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+                crate::qr_code::print_qr_codes(&serialize_to_base64)?;
+                Ok(None)
+            }
         }
     }
 }
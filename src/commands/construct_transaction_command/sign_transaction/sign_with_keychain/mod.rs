@@ -1,7 +1,5 @@
 extern crate dirs;
 
-use serde::Deserialize;
-
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
 #[interactive_clap(skip_default_from_cli)]
@@ -43,15 +41,7 @@ impl SignKeychain {
                 let dir_name = crate::consts::DIR_NAME_KEY_CHAIN;
                 path.push(dir_name);
                 path.push(file_name);
-                let data = std::fs::read_to_string(path).map_err(|err| {
-                    color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
-                })?;
-                let account_json: User = serde_json::from_str(&data).map_err(|err| {
-                    color_eyre::Report::msg(format!(
-                        "Data for the access key was not found in the file! Error: {}",
-                        err
-                    ))
-                })?;
+                let account_json = crate::common::read_keychain_access_key(&path)?;
 
                 let nonce: u64 = match optional_clap_variant
                     .clone()
@@ -75,11 +65,97 @@ impl SignKeychain {
     }
 }
 
-#[derive(Debug, Deserialize)]
-struct User {
-    account_id: near_primitives::types::AccountId,
-    public_key: near_crypto::PublicKey,
-    private_key: near_crypto::SecretKey,
+/// Looks in the keychain for a function-call access key which is allowed to sign the transaction.
+/// The candidate is chosen by the permission stored in the keychain, only the chosen key is
+/// checked on chain.
+async fn find_function_call_access_key(
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+    network_connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<Option<crate::common::KeychainAccessKey>> {
+    if !is_function_call_only(unsigned_transaction) {
+        return Ok(None);
+    }
+    let access_key = match crate::common::keychain_access_keys(
+        network_connection_config,
+        unsigned_transaction.signer_id.as_ref(),
+    )
+    .into_iter()
+    .find(|access_key| match &access_key.permission {
+        Some(permission) => is_function_call_allowed(permission, unsigned_transaction),
+        None => false,
+    }) {
+        Some(access_key) => access_key,
+        None => return Ok(None),
+    };
+    // The allowance changes over time, so the permission is checked against the one on chain
+    let query_view_access_key_response =
+        near_jsonrpc_client::JsonRpcClient::connect(&network_connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: unsigned_transaction.signer_id.clone(),
+                    public_key: access_key.public_key.clone(),
+                },
+            })
+            .await;
+    match query_view_access_key_response {
+        Ok(near_jsonrpc_primitives::types::query::RpcQueryResponse {
+            kind:
+                near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view),
+            ..
+        }) if is_function_call_allowed(&access_key_view.permission, unsigned_transaction) => {
+            println!(
+                "The transaction will be signed with the function-call access key {}",
+                access_key.public_key
+            );
+            Ok(Some(access_key))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Only transactions made of function calls without a deposit may be signed with a function-call access key
+fn is_function_call_only(unsigned_transaction: &near_primitives::transaction::Transaction) -> bool {
+    !unsigned_transaction.actions.is_empty()
+        && unsigned_transaction
+            .actions
+            .iter()
+            .all(|action| match action {
+                near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                    function_call_action.deposit == 0
+                }
+                _ => false,
+            })
+}
+
+/// Function-call access keys may only sign transactions that call the allowed methods of their receiver without a deposit
+fn is_function_call_allowed(
+    permission: &near_primitives::views::AccessKeyPermissionView,
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+) -> bool {
+    match permission {
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: _,
+            receiver_id,
+            method_names,
+        } => {
+            &unsigned_transaction.receiver_id.to_string() == receiver_id
+                && is_function_call_only(unsigned_transaction)
+                && unsigned_transaction
+                    .actions
+                    .iter()
+                    .all(|action| match action {
+                        near_primitives::transaction::Action::FunctionCall(
+                            function_call_action,
+                        ) => {
+                            method_names.is_empty()
+                                || method_names.contains(&function_call_action.method_name)
+                        }
+                        _ => false,
+                    })
+        }
+        near_primitives::views::AccessKeyPermissionView::FullAccess => false,
+    }
 }
 
 impl SignKeychain {
@@ -88,24 +164,31 @@ impl SignKeychain {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
-        let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
-        let file_name = format!("{}.json", prepopulated_unsigned_transaction.signer_id);
-        let mut path = std::path::PathBuf::from(&home_dir);
-
-        let data_path: std::path::PathBuf = match &connection_config {
+        let account_json: crate::common::KeychainAccessKey = match &connection_config {
             None => {
-                let dir_name = crate::consts::DIR_NAME_KEY_CHAIN;
-                path.push(dir_name);
-                path.push(file_name);
-                path
+                let mut path = std::path::PathBuf::from(
+                    dirs::home_dir().expect("Impossible to get your home dir!"),
+                );
+                path.push(crate::consts::DIR_NAME_KEY_CHAIN);
+                path.push(format!(
+                    "{}.json",
+                    prepopulated_unsigned_transaction.signer_id
+                ));
+                crate::common::read_keychain_access_key(&path)?
             }
             Some(network_connection_config) => {
-                let dir_name = network_connection_config.dir_name();
-                path.push(dir_name);
-                path.push(file_name);
-
-                if path.exists() {
-                    path
+                let signer_id = prepopulated_unsigned_transaction.signer_id.to_string();
+                let account_path =
+                    crate::common::keychain_account_path(network_connection_config, &signer_id);
+                if let Some(function_call_access_key) = find_function_call_access_key(
+                    &prepopulated_unsigned_transaction,
+                    network_connection_config,
+                )
+                .await?
+                {
+                    function_call_access_key
+                } else if account_path.exists() {
+                    crate::common::read_keychain_access_key(&account_path)?
                 } else {
                     let query_view_method_response = near_jsonrpc_client::JsonRpcClient::connect(
                         &network_connection_config.rpc_url().as_str(),
@@ -132,55 +215,31 @@ impl SignKeychain {
                         } else {
                             return Err(color_eyre::Report::msg(format!("Error call result")));
                         };
-                    let mut path = std::path::PathBuf::from(&home_dir);
-                    path.push(dir_name);
-                    path.push(&prepopulated_unsigned_transaction.signer_id.to_string());
-                    let mut data_path = std::path::PathBuf::new();
-                    'outer: for access_key in access_key_view.keys {
-                        let account_public_key = access_key.public_key.to_string();
-                        let is_full_access_key: bool = match &access_key.access_key.permission {
-                            near_primitives::views::AccessKeyPermissionView::FullAccess => true,
-                            near_primitives::views::AccessKeyPermissionView::FunctionCall {
-                                allowance: _,
-                                receiver_id: _,
-                                method_names: _,
-                            } => false,
-                        };
-                        let dir = path
-                            .read_dir()
-                            .map_err(|err| {
-                                color_eyre::Report::msg(format!("There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain. {}", err))
-                            })?;
-                        for entry in dir {
-                            if let Ok(entry) = entry {
-                                if entry
-                                    .path()
-                                    .file_stem()
-                                    .unwrap()
-                                    .to_str()
-                                    .unwrap()
-                                    .contains(account_public_key.rsplit(':').next().unwrap())
-                                    && is_full_access_key
-                                {
-                                    data_path.push(entry.path());
-                                    break 'outer;
-                                }
-                            } else {
-                                return Err(color_eyre::Report::msg(format!(
-                                    "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
-                                )));
-                            };
-                        }
-                    }
-                    data_path
+                    access_key_view
+                        .keys
+                        .iter()
+                        .filter(|access_key| {
+                            matches!(
+                                access_key.access_key.permission,
+                                near_primitives::views::AccessKeyPermissionView::FullAccess
+                            )
+                        })
+                        .find_map(|access_key| {
+                            crate::common::find_keychain_access_key(
+                                network_connection_config,
+                                &signer_id,
+                                &access_key.public_key,
+                            )
+                            .ok()
+                        })
+                        .ok_or_else(|| {
+                            color_eyre::Report::msg(format!(
+                                "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
+                            ))
+                        })?
                 }
             }
         };
-        let data = std::fs::read_to_string(data_path).map_err(|err| {
-            color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
-        })?;
-        let account_json: User = serde_json::from_str(&data)
-            .map_err(|err| color_eyre::Report::msg(format!("Error reading data: {}", err)))?;
         let sign_with_private_key = super::sign_with_private_key::SignPrivateKey {
             signer_public_key: crate::types::public_key::PublicKey(account_json.public_key),
             signer_private_key: crate::types::secret_key::SecretKey(account_json.private_key),
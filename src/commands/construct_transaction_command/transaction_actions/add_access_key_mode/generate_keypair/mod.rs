@@ -2,23 +2,84 @@ use std::str::FromStr;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = crate::common::SignerContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct GenerateKeypair {
+    #[interactive_clap(long)]
+    ///The type of the key pair: ed25519 or secp256k1
+    pub key_type: crate::types::key_type::KeyType,
+    ///The seed phrase to derive the key pair from; a new one is generated if it is missing
+    #[interactive_clap(long)]
+    pub master_seed_phrase: Option<String>,
+    ///The HD path of the key pair (m/44'/397'/0' by default)
+    #[interactive_clap(long)]
+    pub seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
+    ///The optional BIP39 passphrase; it is not stored, keep it to recover the key pair
+    #[interactive_clap(long)]
+    pub passphrase: Option<String>,
     #[interactive_clap(subcommand)]
     pub permission: super::add_access_key::AccessKeyPermission,
 }
 
 impl GenerateKeypair {
+    pub fn from_cli(
+        optional_clap_variant: Option<<GenerateKeypair as interactive_clap::ToCli>::CliVariant>,
+        context: crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let key_type = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.key_type)
+        {
+            Some(key_type) => key_type,
+            None => Self::input_key_type(&context)?,
+        };
+        let master_seed_phrase = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.master_seed_phrase);
+        let seed_phrase_hd_path = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.seed_phrase_hd_path);
+        let passphrase = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.passphrase);
+        let permission = super::add_access_key::AccessKeyPermission::from_cli(
+            optional_clap_variant.and_then(|clap_variant| clap_variant.permission),
+            context,
+        )?;
+        Ok(Self {
+            key_type,
+            master_seed_phrase,
+            seed_phrase_hd_path,
+            passphrase,
+            permission,
+        })
+    }
+
+    fn input_key_type(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::key_type::KeyType> {
+        crate::types::key_type::input_key_type()
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair().await?;
+            crate::common::generate_keypair(&crate::common::KeyDerivationParams {
+                key_type: self.key_type.into(),
+                ..crate::common::KeyDerivationParams::from_seed_phrase_options(
+                    self.master_seed_phrase.clone(),
+                    self.seed_phrase_hd_path.clone(),
+                    self.passphrase.clone(),
+                )
+            })
+            .await?;
         crate::common::save_access_key_to_keychain(
             network_connection_config.clone(),
             key_pair_properties.clone(),
             &prepopulated_unsigned_transaction.receiver_id.to_string(),
+            near_primitives::views::AccessKeyPermissionView::FullAccess,
         )
         .await
         .map_err(|err| {
@@ -0,0 +1,36 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct WasmFile {
+    pub file_path: crate::types::path_buf::PathBuf,
+}
+
+impl WasmFile {
+    fn input_file_path(_context: &()) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let input_file_path: String = Input::new()
+            .with_prompt("What is the file location of the contract?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(input_file_path).into())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let code = std::fs::read(&self.file_path.0).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to open or read the file: {:?}", err))
+        })?;
+        let wasm_info = crate::common::inspect_wasm(&code)?;
+        println!("\nContract file: {}", self.file_path);
+        super::display_wasm_info(&wasm_info);
+        if wasm_info
+            .exported_functions
+            .iter()
+            .any(|function| function == "contract_source_metadata")
+        {
+            println!("\nThe contract exposes NEP-330 source metadata, inspect the deployed contract to read it");
+        } else {
+            println!("\nThe contract does not expose NEP-330 source metadata");
+        }
+        Ok(())
+    }
+}
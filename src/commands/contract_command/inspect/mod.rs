@@ -0,0 +1,66 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod file;
+mod on_chain;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Inspect {
+    #[interactive_clap(subcommand)]
+    pub source: InspectSource,
+}
+
+impl Inspect {
+    pub async fn process(self) -> crate::CliResult {
+        self.source.process().await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///Where is the contract to inspect?
+pub enum InspectSource {
+    #[strum_discriminants(strum(message = "A local wasm file"))]
+    ///Inspect a local wasm file
+    File(self::file::WasmFile),
+    #[strum_discriminants(strum(message = "A contract deployed to an account"))]
+    ///Inspect the contract deployed to an account
+    OnChain(self::on_chain::operation_mode::OperationMode),
+}
+
+impl InspectSource {
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::File(wasm_file) => wasm_file.process().await,
+            Self::OnChain(operation_mode) => operation_mode.process().await,
+        }
+    }
+}
+
+fn display_wasm_info(wasm_info: &crate::common::WasmInfo) {
+    println!("Size: {} bytes", wasm_info.size);
+    for (initial, maximum) in &wasm_info.memories {
+        match maximum {
+            Some(maximum) => println!(
+                "Memory: {} initial, {} maximum pages of 64 KiB",
+                initial, maximum
+            ),
+            None => println!("Memory: {} initial pages of 64 KiB, no maximum", initial),
+        }
+    }
+    println!(
+        "\nExported functions ({}):",
+        wasm_info.exported_functions.len()
+    );
+    for function in &wasm_info.exported_functions {
+        println!("  {}", function);
+    }
+    println!(
+        "\nImported host functions ({}):",
+        wasm_info.imported_functions.len()
+    );
+    for (module, function) in &wasm_info.imported_functions {
+        println!("  {}::{}", module, function);
+    }
+}
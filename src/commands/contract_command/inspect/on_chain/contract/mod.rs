@@ -0,0 +1,83 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ContractInspectCommandNetworkContext)]
+pub struct Contract {
+    #[interactive_clap(skip_default_from_cli)]
+    pub contract_account_id: crate::types::account_id::AccountId,
+}
+
+impl Contract {
+    fn from_cli_contract_account_id(
+        optional_cli_contract_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::ContractInspectCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_contract_account_id {
+            Some(cli_contract_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_contract_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_contract_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_contract_account_id);
+                    Self::input_contract_account_id(&context)
+                }
+            },
+            None => Self::input_contract_account_id(&context),
+        }
+    }
+
+    pub fn input_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::ContractInspectCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What contract do you want to inspect?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            };
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let code = crate::common::get_contract_code(
+            &network_connection_config,
+            self.contract_account_id.clone().into(),
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        let wasm_info = crate::common::inspect_wasm(&code)?;
+        println!("\nContract deployed to <{}>:", self.contract_account_id);
+        super::super::display_wasm_info(&wasm_info);
+        if wasm_info
+            .exported_functions
+            .iter()
+            .any(|function| function == "contract_source_metadata")
+        {
+            match super::super::super::get_contract_source_metadata(
+                &network_connection_config,
+                self.contract_account_id.into(),
+            )
+            .await
+            {
+                Ok(Some(source_metadata)) => {
+                    println!("\nContract source metadata (NEP-330):\n{}", source_metadata)
+                }
+                Ok(None) => println!("\nThe contract does not expose NEP-330 source metadata"),
+                Err(err) => println!("\nFailed to read the contract source metadata: {}", err),
+            }
+        } else {
+            println!("\nThe contract does not expose NEP-330 source metadata");
+        }
+        Ok(())
+    }
+}
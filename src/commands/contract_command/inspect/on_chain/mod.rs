@@ -0,0 +1,2 @@
+mod contract;
+pub mod operation_mode;
@@ -0,0 +1,146 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod inspect;
+mod upgrade;
+mod verify;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct ContractActions {
+    #[interactive_clap(subcommand)]
+    pub action: ContractAction,
+}
+
+impl ContractActions {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.action.process(prepopulated_unsigned_transaction).await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///What do you want to do with the contract?
+pub enum ContractAction {
+    #[strum_discriminants(strum(
+        message = "Inspect a contract: exported methods, imports, memory and source metadata"
+    ))]
+    ///Inspect a local or deployed contract
+    Inspect(self::inspect::Inspect),
+    #[strum_discriminants(strum(
+        message = "Verify that a deployed contract was built from the given code"
+    ))]
+    ///Compare the deployed contract with a local wasm file or a build from source
+    Verify(self::verify::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Upgrade a contract: deploy new code and call a migration method in one transaction"
+    ))]
+    ///Deploy new contract code and call its migration method in one transaction
+    Upgrade(self::upgrade::operation_mode::OperationMode),
+}
+
+impl ContractAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        match self {
+            Self::Inspect(inspect) => inspect.process().await,
+            Self::Verify(operation_mode) => operation_mode.process().await,
+            Self::Upgrade(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+        }
+    }
+}
+
+/// Contract source metadata (NEP-330)
+#[derive(Debug, serde::Deserialize)]
+pub struct ContractSourceMetadata {
+    pub version: Option<String>,
+    pub link: Option<String>,
+    #[serde(default)]
+    pub standards: Vec<ContractStandard>,
+    pub build_info: Option<BuildInfo>,
+}
+
+/// How the contract was built, for reproducible-build verification
+#[derive(Debug, serde::Deserialize)]
+pub struct BuildInfo {
+    pub build_environment: String,
+    pub build_command: Vec<String>,
+    pub contract_path: Option<String>,
+    /// Repository URL and commit, e.g. `git+https://github.com/org/repo?rev=<commit>`
+    pub source_code_snapshot: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ContractStandard {
+    pub standard: String,
+    pub version: String,
+}
+
+impl std::fmt::Display for ContractSourceMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Version: {}",
+            self.version.as_deref().unwrap_or("not specified")
+        )?;
+        write!(
+            f,
+            "Source code: {}",
+            self.link.as_deref().unwrap_or("not specified")
+        )?;
+        for standard in &self.standards {
+            write!(f, "\nStandard: {} {}", standard.standard, standard.version)?;
+        }
+        if let Some(build_info) = &self.build_info {
+            write!(
+                f,
+                "\nSource code snapshot: {}",
+                build_info.source_code_snapshot
+            )?;
+            write!(f, "\nBuild environment: {}", build_info.build_environment)?;
+            write!(
+                f,
+                "\nBuild command: {}",
+                shell_words::join(&build_info.build_command)
+            )?;
+            if let Some(contract_path) = &build_info.contract_path {
+                write!(f, "\nContract path: {}", contract_path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the NEP-330 source metadata, returns None if the contract does not expose
+/// `contract_source_metadata`
+async fn get_contract_source_metadata(
+    connection_config: &crate::common::ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<ContractSourceMetadata>> {
+    let result = match crate::common::call_view_function_if_exists(
+        connection_config,
+        contract_account_id,
+        "contract_source_metadata",
+        b"{}".to_vec(),
+    )
+    .await?
+    {
+        Some(result) => result,
+        None => return Ok(None),
+    };
+    serde_json::from_slice(&result).map(Some).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Failed to parse the contract source metadata: {}",
+            err
+        ))
+    })
+}
@@ -0,0 +1,109 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct ContractFile {
+    pub file_path: crate::types::path_buf::PathBuf,
+    ///Skip the checks against the protocol limits and the deployed code (true or false)
+    #[interactive_clap(long)]
+    skip_checks: Option<bool>,
+    ///Check that the new code exports the migration method before sending (true or false)
+    #[interactive_clap(long)]
+    check_migrate_method: Option<bool>,
+    #[interactive_clap(named_arg)]
+    ///Specify a migration method
+    migrate: super::migrate::MigrateAction,
+}
+
+impl ContractFile {
+    pub fn from_cli(
+        optional_clap_variant: Option<<ContractFile as interactive_clap::ToCli>::CliVariant>,
+        context: crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let file_path = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.file_path)
+        {
+            Some(file_path) => file_path,
+            None => Self::input_file_path(&context)?,
+        };
+        let skip_checks = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.skip_checks);
+        let check_migrate_method = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.check_migrate_method);
+        let migrate = super::migrate::MigrateAction::from_cli(
+            optional_clap_variant.and_then(|clap_variant| match clap_variant.migrate {
+                Some(ClapNamedArgMigrateActionForContractFile::Migrate(cli_args)) => Some(cli_args),
+                None => None,
+            }),
+            context,
+        )?;
+        Ok(Self {
+            file_path,
+            skip_checks,
+            check_migrate_method,
+            migrate,
+        })
+    }
+}
+
+impl ContractFile {
+    fn input_file_path(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let input_file_path: String = Input::new()
+            .with_prompt("What is a file location of the new contract code?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(input_file_path).into())
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let code = std::fs::read(&self.file_path.0.clone()).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to open or read the file: {:?}.\nError: {:?}",
+                &self.file_path.0.clone(),
+                err
+            ))
+        })?;
+        let wasm_info = crate::common::inspect_wasm(&code)?;
+        if self.check_migrate_method.unwrap_or(false) && !self.migrate.check_exported(&wasm_info)? {
+            return Ok(());
+        }
+        let deploy_code = match &network_connection_config {
+            Some(connection_config) if !self.skip_checks.unwrap_or(false) => {
+                crate::common::check_code_before_deploy(
+                    connection_config,
+                    prepopulated_unsigned_transaction.receiver_id.clone(),
+                    &code,
+                )
+                .await?
+            }
+            _ => true,
+        };
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        if deploy_code {
+            actions.push(near_primitives::transaction::Action::DeployContract(
+                near_primitives::transaction::DeployContractAction { code },
+            ));
+        } else {
+            println!(
+                "\nThe contract code is not deployed again, only the migration method is called"
+            );
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        self.migrate
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,124 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+/// The migration method is called in the same transaction as the deployment,
+/// so the new code is reverted if the migration fails
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct MigrateAction {
+    method_name: String,
+    args: String,
+    #[interactive_clap(long = "prepaid-gas")]
+    gas: crate::common::NearGas,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl MigrateAction {
+    fn input_method_name(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<String> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter the migration method name")
+            .with_initial_text("migrate")
+            .interact_text()?)
+    }
+
+    fn input_args(_context: &crate::common::SignerContext) -> color_eyre::eyre::Result<String> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter args for the migration method")
+            .with_initial_text("{}")
+            .interact_text()?)
+    }
+
+    fn input_gas(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearGas> {
+        println!();
+        let gas: u64 = loop {
+            let input_gas: crate::common::NearGas = Input::new()
+                .with_prompt("Enter a gas for the migration method")
+                .with_initial_text("100 TeraGas")
+                .interact_text()?;
+            let gas: u64 = match input_gas {
+                crate::common::NearGas { inner: num } => num,
+            };
+            if gas <= 300000000000000 {
+                break gas;
+            } else {
+                println!("You need to enter a value of no more than 300 TERAGAS")
+            }
+        };
+        Ok(gas.into())
+    }
+
+    /// Checks that the new code exports the migration method.
+    /// Returns false if it does not and the upgrade should be cancelled,
+    /// without a terminal to ask on a missing method is an error.
+    pub fn check_exported(
+        &self,
+        wasm_info: &crate::common::WasmInfo,
+    ) -> color_eyre::eyre::Result<bool> {
+        if wasm_info
+            .exported_functions
+            .iter()
+            .any(|function| function == &self.method_name)
+        {
+            return Ok(true);
+        }
+        println!(
+            "\nThe new contract code does not export the migration method <{}>, the transaction will fail",
+            self.method_name
+        );
+        if !dialoguer::console::Term::stderr().is_term() {
+            return Err(color_eyre::Report::msg(format!(
+                "The new contract code does not export the migration method <{}>",
+                self.method_name
+            )));
+        }
+        let choose_input = vec!["No, cancel the upgrade", "Yes, I want to send it anyway"];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to continue?")
+            .items(&choose_input)
+            .default(0)
+            .interact()?;
+        Ok(select_choose_input == 1)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let action = near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: self.method_name.clone(),
+                args: self.args.clone().into_bytes(),
+                gas: self.gas.clone().inner,
+                deposit: 0,
+            },
+        );
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(action);
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(unsigned_transaction, network_connection_config.clone())
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    network_connection_config,
+                );
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
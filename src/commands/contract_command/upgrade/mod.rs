@@ -0,0 +1,4 @@
+mod contract;
+mod migrate;
+pub mod operation_mode;
+mod sender;
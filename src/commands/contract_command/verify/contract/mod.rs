@@ -0,0 +1,124 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext)]
+pub struct Contract {
+    #[interactive_clap(skip_default_from_cli)]
+    pub contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub source: super::source::LocalSource,
+}
+
+impl Contract {
+    fn from_cli_contract_account_id(
+        optional_cli_contract_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_contract_account_id {
+            Some(cli_contract_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_contract_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_contract_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_contract_account_id);
+                    Self::input_contract_account_id(&context)
+                }
+            },
+            None => Self::input_contract_account_id(&context),
+        }
+    }
+
+    pub fn input_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What contract do you want to verify?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            };
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let code = self.source.read_code()?;
+        let account_view = near_jsonrpc_client::JsonRpcClient::connect(
+            network_connection_config.rpc_url().as_str(),
+        )
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccount {
+                account_id: self.contract_account_id.clone().into(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch query for view account: {:?}", err))
+        })?;
+        let account_view =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
+                account_view.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
+            return Err(color_eyre::Report::msg(format!(
+                "Contract code is not deployed to this account <{}>",
+                self.contract_account_id
+            )));
+        }
+
+        let local_code_hash = near_primitives::hash::hash(&code);
+        let verified = local_code_hash == account_view.code_hash;
+        println!(
+            "\nVerification of the contract deployed to <{}>:",
+            self.contract_account_id
+        );
+        println!("Deployed code hash: {}", account_view.code_hash);
+        println!(
+            "Local code hash:    {} ({} bytes)",
+            local_code_hash,
+            code.len()
+        );
+        println!(
+            "Result: {}",
+            if verified {
+                "PASS, the local code matches the deployed contract"
+            } else {
+                "FAIL, the local code differs from the deployed contract"
+            }
+        );
+        match super::super::get_contract_source_metadata(
+            &network_connection_config,
+            self.contract_account_id.clone().into(),
+        )
+        .await
+        {
+            Ok(Some(source_metadata)) => {
+                println!("\nContract source metadata (NEP-330):\n{}", source_metadata)
+            }
+            Ok(None) => println!("\nThe contract does not expose NEP-330 source metadata"),
+            Err(err) => println!("\nFailed to read the contract source metadata: {}", err),
+        }
+        if verified {
+            Ok(())
+        } else {
+            Err(color_eyre::Report::msg(format!(
+                "The contract deployed to <{}> was not built from the given source",
+                self.contract_account_id
+            )))
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod contract;
+pub mod operation_mode;
+mod source;
@@ -0,0 +1,121 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext)]
+///What do you want to compare the deployed contract with?
+pub enum LocalSource {
+    #[strum_discriminants(strum(message = "A local wasm file"))]
+    ///Compare with a local wasm file
+    WasmFile(WasmFile),
+    #[strum_discriminants(strum(message = "A source directory and its build command"))]
+    ///Build the contract from the source directory and compare with the built wasm file
+    SourceDir(SourceDir),
+}
+
+impl LocalSource {
+    pub fn read_code(&self) -> color_eyre::eyre::Result<Vec<u8>> {
+        match self {
+            Self::WasmFile(wasm_file) => read_wasm_file(&wasm_file.file_path.0),
+            Self::SourceDir(source_dir) => source_dir.build(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext)]
+pub struct WasmFile {
+    pub file_path: crate::types::path_buf::PathBuf,
+}
+
+impl WasmFile {
+    fn input_file_path(
+        _context: &super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let input_file_path: String = Input::new()
+            .with_prompt("What is the file location of the contract?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(input_file_path).into())
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext)]
+pub struct SourceDir {
+    ///The directory with the contract source code
+    pub source_dir: crate::types::path_buf::PathBuf,
+    ///The command that builds the contract, run in the source directory
+    pub build_command: String,
+    ///The built wasm file, relative to the source directory
+    pub wasm_file: crate::types::path_buf::PathBuf,
+}
+
+impl SourceDir {
+    fn input_source_dir(
+        _context: &super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        println!();
+        let input_source_dir: String = Input::new()
+            .with_prompt("Where is the contract source code?")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(input_source_dir).into())
+    }
+
+    fn input_build_command(
+        _context: &super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("What command builds the contract?")
+            .with_initial_text("cargo build --target wasm32-unknown-unknown --release")
+            .interact_text()?)
+    }
+
+    fn input_wasm_file(
+        _context: &super::operation_mode::online_mode::select_server::ContractVerifyCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        let input_wasm_file: String = Input::new()
+            .with_prompt(
+                "Where does the build put the wasm file (relative to the source directory)?",
+            )
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(input_wasm_file).into())
+    }
+
+    fn build(&self) -> color_eyre::eyre::Result<Vec<u8>> {
+        let build_command = shell_words::split(&self.build_command).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to parse the build command: {}", err))
+        })?;
+        let (program, args) = build_command
+            .split_first()
+            .ok_or_else(|| color_eyre::Report::msg("The build command is empty"))?;
+        println!(
+            "\nBuilding the contract in {}: {}",
+            self.source_dir, self.build_command
+        );
+        let status = std::process::Command::new(program)
+            .args(args)
+            .current_dir(&self.source_dir.0)
+            .status()
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to run the build command: {}", err))
+            })?;
+        if !status.success() {
+            return Err(color_eyre::Report::msg(format!(
+                "The build command failed: {}",
+                status
+            )));
+        }
+        read_wasm_file(&self.source_dir.0.join(&self.wasm_file.0))
+    }
+}
+
+fn read_wasm_file(file_path: &std::path::Path) -> color_eyre::eyre::Result<Vec<u8>> {
+    std::fs::read(file_path).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Failed to open or read the file: {:?}.\nError: {:?}",
+            file_path, err
+        ))
+    })
+}
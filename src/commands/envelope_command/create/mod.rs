@@ -0,0 +1,75 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Create {
+    ///The base64-encoded unsigned transaction
+    unsigned_transaction: crate::common::TransactionAsBase64,
+    ///The network the transaction is for: testnet, mainnet, betanet, sandbox or an RPC URL
+    network: String,
+    ///What the transaction does, for the people who will sign it
+    description: String,
+    ///Who created the envelope
+    creator: String,
+    ///The envelope file to write
+    output_file: crate::types::path_buf::PathBuf,
+}
+
+impl Create {
+    fn input_unsigned_transaction(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::common::TransactionAsBase64> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("Enter the base64-encoded unsigned transaction")
+            .interact_text()?)
+    }
+
+    fn input_network(_context: &()) -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("What network is the transaction for? (testnet, mainnet, betanet, sandbox or an RPC URL)")
+            .with_initial_text("testnet")
+            .validate_with(|network: &String| {
+                crate::common::ConnectionConfig::from_network_name(network)
+                    .map(|_| ())
+                    .map_err(|err| err.to_string())
+            })
+            .interact_text()?)
+    }
+
+    fn input_description(_context: &()) -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("Describe what the transaction does")
+            .interact_text()?)
+    }
+
+    fn input_creator(_context: &()) -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("Who is creating the envelope?")
+            .interact_text()?)
+    }
+
+    fn input_output_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        let output_file: String = Input::new()
+            .with_prompt("Where do you want to save the envelope?")
+            .with_initial_text("transaction-envelope.json")
+            .interact_text()?;
+        Ok(std::path::PathBuf::from(output_file).into())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let connection_config = crate::common::ConnectionConfig::from_network_name(&self.network)?;
+        let envelope = super::TransactionEnvelope::new(
+            &self.unsigned_transaction.inner,
+            &connection_config,
+            self.description,
+            self.creator,
+        );
+        println!("\nUnsigned transaction:");
+        crate::common::print_transaction(self.unsigned_transaction.inner);
+        println!("{:<13} {}", "hash:", envelope.transaction_hash);
+        envelope.write(&self.output_file.0)
+    }
+}
@@ -0,0 +1,79 @@
+use near_primitives::borsh::BorshSerialize;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Finalize {
+    ///The signed envelope file
+    envelope_file: crate::types::path_buf::PathBuf,
+    #[interactive_clap(subcommand)]
+    submit: FinalizeSubmit,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///How would you like to proceed?
+pub enum FinalizeSubmit {
+    #[strum_discriminants(strum(message = "I want to send the transaction to the network"))]
+    ///Send the signed transaction to the network of the envelope
+    Send,
+    #[strum_discriminants(strum(
+        message = "I only want to print base64-encoded signed transaction and exit"
+    ))]
+    ///Print the base64-encoded signed transaction
+    Display,
+}
+
+impl Finalize {
+    fn input_envelope_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        super::input_envelope_file()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let envelope = super::TransactionEnvelope::read(&self.envelope_file.0)?;
+        let signed_transaction = envelope.signed_transaction()?;
+        println!("\nSigned transaction:");
+        crate::common::print_transaction(signed_transaction.transaction.clone());
+        println!("{:<13} {}", "signature:", signed_transaction.signature);
+        match self.submit {
+            FinalizeSubmit::Display => {
+                println!(
+                    "\nBase64-encoded signed transaction:\n{}",
+                    near_primitives::serialize::to_base64(
+                        signed_transaction
+                            .try_to_vec()
+                            .expect("Transaction is not expected to fail on serialization"),
+                    )
+                );
+            }
+            FinalizeSubmit::Send => {
+                let connection_config = envelope.connection_config()?;
+                println!("Transaction sent ...");
+                let json_rpc_client = near_jsonrpc_client::JsonRpcClient::connect(
+                    connection_config.rpc_url().as_str(),
+                );
+                let transaction_info = loop {
+                    let transaction_info_result = json_rpc_client
+                        .call(near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest{signed_transaction: signed_transaction.clone()})
+                        .await;
+                    match transaction_info_result {
+                        Ok(response) => {
+                            break response;
+                        }
+                        Err(err) => match crate::common::rpc_transaction_error(err) {
+                            Ok(_) => {
+                                actix::clock::sleep(std::time::Duration::from_millis(100)).await
+                            }
+                            Err(report) => return Err(report),
+                        },
+                    };
+                };
+                crate::common::print_transaction_status(transaction_info, Some(connection_config));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Inspect {
+    ///The envelope file
+    envelope_file: crate::types::path_buf::PathBuf,
+}
+
+impl Inspect {
+    fn input_envelope_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        super::input_envelope_file()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let envelope = super::TransactionEnvelope::read(&self.envelope_file.0)?;
+        println!("\n{:<13} {}", "network:", envelope.network);
+        println!("{:<13} {}", "creator:", envelope.creator);
+        println!("{:<13} {}", "description:", envelope.description);
+        println!("\nUnsigned transaction:");
+        crate::common::print_transaction(envelope.unsigned_transaction()?);
+        println!("{:<13} {}", "hash:", envelope.transaction_hash);
+        if envelope.signatures.is_empty() {
+            println!("\nThe envelope is not signed yet.");
+        } else {
+            println!("\nSignatures:");
+            for signature in &envelope.signatures {
+                let status = match envelope.verify_signature(signature) {
+                    Ok(()) => "valid".to_string(),
+                    Err(err) => format!("INVALID: {}", err),
+                };
+                println!("  {} ({})", signature.public_key, status);
+            }
+        }
+        Ok(())
+    }
+}
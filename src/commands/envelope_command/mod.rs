@@ -0,0 +1,192 @@
+use near_primitives::borsh::BorshSerialize;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod create;
+mod finalize;
+mod inspect;
+mod sign;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct EnvelopeCommand {
+    #[interactive_clap(subcommand)]
+    pub action: EnvelopeAction,
+}
+
+impl EnvelopeCommand {
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///What do you want to do with the transaction envelope?
+pub enum EnvelopeAction {
+    #[strum_discriminants(strum(message = "Create an envelope for an unsigned transaction"))]
+    ///Wrap an unsigned transaction into an envelope file together with its network and description
+    Create(self::create::Create),
+    #[strum_discriminants(strum(message = "Inspect an envelope"))]
+    ///Show the transaction, the description and the signatures of an envelope file
+    Inspect(self::inspect::Inspect),
+    #[strum_discriminants(strum(message = "Sign the transaction of an envelope"))]
+    ///Add a signature to an envelope file
+    Sign(self::sign::Sign),
+    #[strum_discriminants(strum(message = "Finalize an envelope into a signed transaction"))]
+    ///Combine the transaction of an envelope file with its signature
+    Finalize(self::finalize::Finalize),
+}
+
+impl EnvelopeAction {
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::Create(create) => create.process().await,
+            Self::Inspect(inspect) => inspect.process().await,
+            Self::Sign(sign) => sign.process().await,
+            Self::Finalize(finalize) => finalize.process().await,
+        }
+    }
+}
+
+/// An unsigned transaction passed between the people who create, review and sign it
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct TransactionEnvelope {
+    network: String,
+    description: String,
+    creator: String,
+    /// Base64-encoded borsh serialization of the unsigned transaction
+    unsigned_transaction: String,
+    /// The hash the signatures are made for; informational, it is always recomputed
+    transaction_hash: near_primitives::hash::CryptoHash,
+    #[serde(default)]
+    signatures: Vec<EnvelopeSignature>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct EnvelopeSignature {
+    public_key: near_crypto::PublicKey,
+    signature: near_crypto::Signature,
+}
+
+impl TransactionEnvelope {
+    fn new(
+        unsigned_transaction: &near_primitives::transaction::Transaction,
+        connection_config: &crate::common::ConnectionConfig,
+        description: String,
+        creator: String,
+    ) -> Self {
+        Self {
+            network: connection_config.network_name(),
+            description,
+            creator,
+            unsigned_transaction: near_primitives::serialize::to_base64(
+                unsigned_transaction
+                    .try_to_vec()
+                    .expect("Transaction is not expected to fail on serialization"),
+            ),
+            transaction_hash: unsigned_transaction.get_hash_and_size().0,
+            signatures: vec![],
+        }
+    }
+
+    fn read(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(file_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to open or read the envelope file {}: {}",
+                file_path.display(),
+                err
+            ))
+        })?;
+        let envelope: Self = serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to parse the envelope file: {}", err))
+        })?;
+        if envelope.unsigned_transaction()?.get_hash_and_size().0 != envelope.transaction_hash {
+            return Err(color_eyre::Report::msg(
+                "The transaction hash of the envelope does not match its transaction",
+            ));
+        }
+        Ok(envelope)
+    }
+
+    fn write(&self, file_path: &std::path::Path) -> crate::CliResult {
+        std::fs::write(file_path, serde_json::to_string_pretty(self)?).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to write the envelope file {}: {}",
+                file_path.display(),
+                err
+            ))
+        })?;
+        println!("The envelope is saved in a file {}", file_path.display());
+        Ok(())
+    }
+
+    fn connection_config(&self) -> color_eyre::eyre::Result<crate::common::ConnectionConfig> {
+        crate::common::ConnectionConfig::from_network_name(&self.network)
+    }
+
+    fn unsigned_transaction(
+        &self,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
+        let unsigned_transaction: crate::common::TransactionAsBase64 = self
+            .unsigned_transaction
+            .parse()
+            .map_err(color_eyre::Report::msg)?;
+        Ok(unsigned_transaction.inner)
+    }
+
+    /// Checks that the signature is made for the transaction hash with its access key
+    fn verify_signature(&self, signature: &EnvelopeSignature) -> crate::CliResult {
+        let unsigned_transaction = self.unsigned_transaction()?;
+        if signature.public_key != unsigned_transaction.public_key {
+            return Err(color_eyre::Report::msg(format!(
+                "The signature is made with the key <{}>, but the transaction is to be signed with <{}>",
+                signature.public_key, unsigned_transaction.public_key
+            )));
+        }
+        if !crate::common::verify_message_signature(
+            self.transaction_hash.as_ref(),
+            true,
+            &signature.signature,
+            &signature.public_key,
+        ) {
+            return Err(color_eyre::Report::msg(format!(
+                "The signature made with the key <{}> does not match the transaction hash {}",
+                signature.public_key, self.transaction_hash
+            )));
+        }
+        Ok(())
+    }
+
+    fn add_signature(&mut self, signature: EnvelopeSignature) -> crate::CliResult {
+        self.verify_signature(&signature)?;
+        self.signatures
+            .retain(|existing| existing.public_key != signature.public_key);
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    fn signed_transaction(
+        &self,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+        let signature = self
+            .signatures
+            .iter()
+            .find(|signature| self.verify_signature(signature).is_ok())
+            .ok_or_else(|| {
+                color_eyre::Report::msg("The envelope has no valid signature for its transaction")
+            })?;
+        Ok(near_primitives::transaction::SignedTransaction::new(
+            signature.signature.clone(),
+            self.unsigned_transaction()?,
+        ))
+    }
+}
+
+fn input_envelope_file() -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+    println!();
+    let envelope_file: String = dialoguer::Input::new()
+        .with_prompt("What is the location of the envelope file?")
+        .interact_text()?;
+    Ok(std::path::PathBuf::from(envelope_file).into())
+}
@@ -0,0 +1,172 @@
+use dialoguer::Input;
+#[cfg(feature = "ledger")]
+use near_primitives::borsh::BorshSerialize;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct Sign {
+    ///The envelope file; the signature is added to it
+    envelope_file: crate::types::path_buf::PathBuf,
+    #[interactive_clap(subcommand)]
+    signer: Signer,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///How do you want to sign the transaction?
+pub enum Signer {
+    #[strum_discriminants(strum(message = "Sign with a private key"))]
+    ///Sign the transaction with a private key
+    PrivateKey(SignPrivateKey),
+    #[strum_discriminants(strum(message = "Sign with the key stored in the keychain"))]
+    ///Sign the transaction with the access key stored in the keychain for the network of the envelope
+    Keychain,
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(message = "Sign with Ledger Nano S/X device"))]
+    ///Sign the transaction with a Ledger device
+    Ledger(SignLedger),
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct SignPrivateKey {
+    ///The private key of the access key the transaction is to be signed with
+    signer_private_key: crate::types::secret_key::SecretKey,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct SignLedger {
+    ///The HD path of the key on the Ledger device
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+}
+
+impl Sign {
+    fn input_envelope_file(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::path_buf::PathBuf> {
+        super::input_envelope_file()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut envelope = super::TransactionEnvelope::read(&self.envelope_file.0)?;
+        let unsigned_transaction = envelope.unsigned_transaction()?;
+        println!("\n{:<13} {}", "network:", envelope.network);
+        println!("{:<13} {}", "creator:", envelope.creator);
+        println!("{:<13} {}", "description:", envelope.description);
+        println!("\nTransaction to sign:");
+        crate::common::print_transaction(unsigned_transaction.clone());
+
+        let signature = match self.signer {
+            Signer::PrivateKey(sign_private_key) => {
+                sign_with_secret_key(&envelope, sign_private_key.signer_private_key.into())
+            }
+            Signer::Keychain => {
+                let secret_key = keychain_secret_key(&envelope, &unsigned_transaction)?;
+                sign_with_secret_key(&envelope, secret_key)
+            }
+            #[cfg(feature = "ledger")]
+            Signer::Ledger(sign_ledger) => {
+                sign_with_ledger(
+                    &unsigned_transaction,
+                    sign_ledger.seed_phrase_hd_path.into(),
+                )
+                .await?
+            }
+        };
+        envelope.add_signature(signature)?;
+        println!("The signature is verified and added to the envelope.");
+        envelope.write(&self.envelope_file.0)
+    }
+}
+
+impl SignPrivateKey {
+    fn input_signer_private_key(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::secret_key::SecretKey> {
+        Ok(Input::new()
+            .with_prompt("Enter the private key of the signer")
+            .interact_text()?)
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl SignLedger {
+    fn input_seed_phrase_hd_path(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::slip10::BIP32Path> {
+        Ok(Input::new()
+            .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
+            .with_initial_text("44'/397'/0'/0'/1'")
+            .interact_text()?)
+    }
+}
+
+fn sign_with_secret_key(
+    envelope: &super::TransactionEnvelope,
+    secret_key: near_crypto::SecretKey,
+) -> super::EnvelopeSignature {
+    super::EnvelopeSignature {
+        public_key: secret_key.public_key(),
+        signature: secret_key.sign(envelope.transaction_hash.as_ref()),
+    }
+}
+
+/// Finds the key of the transaction in the keychain of the envelope network
+fn keychain_secret_key(
+    envelope: &super::TransactionEnvelope,
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+) -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    let access_key = crate::common::find_keychain_access_key(
+        &envelope.connection_config()?,
+        unsigned_transaction.signer_id.as_ref(),
+        &unsigned_transaction.public_key,
+    )?;
+    Ok(access_key.private_key)
+}
+
+#[cfg(feature = "ledger")]
+async fn sign_with_ledger(
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+    seed_phrase_hd_path: slip10::BIP32Path,
+) -> color_eyre::eyre::Result<super::EnvelopeSignature> {
+    println!(
+        "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+        seed_phrase_hd_path
+    );
+    let public_key = near_ledger::get_public_key(seed_phrase_hd_path.clone())
+        .await
+        .map_err(|near_ledger_error| {
+            color_eyre::Report::msg(format!(
+                "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                near_ledger_error
+            ))
+        })?;
+    println!(
+        "Confirm transaction signing on your Ledger device (HD Path: {})",
+        seed_phrase_hd_path,
+    );
+    let signature = near_ledger::sign_transaction(
+        unsigned_transaction
+            .try_to_vec()
+            .expect("Transaction is not expected to fail on serialization"),
+        seed_phrase_hd_path,
+    )
+    .await
+    .map_err(|near_ledger_error| {
+        color_eyre::Report::msg(format!(
+            "Error occurred while signing the transaction: {:?}",
+            near_ledger_error
+        ))
+    })?;
+    Ok(super::EnvelopeSignature {
+        public_key: near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+            public_key.to_bytes(),
+        )),
+        signature: near_crypto::Signature::from_parts(near_crypto::KeyType::ED25519, &signature)
+            .expect("Signature is not expected to fail on deserialization"),
+    })
+}
@@ -0,0 +1,614 @@
+use std::io::Read;
+
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+/// NEAR ABI of a contract, only the parts needed to call its methods
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AbiRoot {
+    pub schema_version: String,
+    pub body: AbiBody,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AbiBody {
+    pub functions: Vec<AbiFunction>,
+    #[serde(default)]
+    pub root_schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AbiFunction {
+    pub name: String,
+    pub kind: AbiFunctionKind,
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+    #[serde(default)]
+    pub params: AbiParameters,
+    pub result: Option<AbiType>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AbiFunctionKind {
+    View,
+    Call,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "serialization_type", rename_all = "lowercase")]
+pub enum AbiParameters {
+    Json { args: Vec<AbiJsonParameter> },
+    Borsh { args: Vec<serde_json::Value> },
+}
+
+impl Default for AbiParameters {
+    fn default() -> Self {
+        Self::Json { args: vec![] }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AbiJsonParameter {
+    pub name: String,
+    pub type_schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "serialization_type", rename_all = "lowercase")]
+pub enum AbiType {
+    Json { type_schema: serde_json::Value },
+    Borsh { type_schema: serde_json::Value },
+}
+
+impl AbiRoot {
+    pub fn function(&self, method_name: &str) -> Option<&AbiFunction> {
+        self.body
+            .functions
+            .iter()
+            .find(|function| function.name == method_name)
+    }
+
+    /// Asks to choose one of the methods of this kind
+    pub fn input_method_name(&self, kind: AbiFunctionKind) -> color_eyre::eyre::Result<String> {
+        let functions: Vec<&AbiFunction> = self
+            .body
+            .functions
+            .iter()
+            .filter(|function| function.kind == kind)
+            .collect();
+        if functions.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "The contract ABI has no {} methods",
+                match kind {
+                    AbiFunctionKind::View => "view",
+                    AbiFunctionKind::Call => "call",
+                }
+            )));
+        }
+        let items: Vec<String> = functions
+            .iter()
+            .map(|function| self.describe_function(function))
+            .collect();
+        let select_function = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose a method")
+            .items(&items)
+            .default(0)
+            .interact()?;
+        Ok(functions[select_function].name.clone())
+    }
+
+    /// Asks for every argument of the method by name and type and assembles the JSON args
+    pub fn input_args(&self, function: &AbiFunction) -> color_eyre::eyre::Result<String> {
+        let args = match &function.params {
+            AbiParameters::Json { args } => args,
+            AbiParameters::Borsh { .. } => {
+                println!("The method takes Borsh-serialized arguments, they cannot be prompted one by one");
+                return Ok(Input::new()
+                    .with_prompt("Enter args for function")
+                    .interact_text()?);
+            }
+        };
+        let mut object = serde_json::Map::new();
+        for arg in args {
+            let value = loop {
+                let input: String = Input::new()
+                    .with_prompt(format!(
+                        "Enter {} ({})",
+                        arg.name,
+                        self.type_name(&arg.type_schema)
+                    ))
+                    .interact_text()?;
+                let value = if self.is_string_type(&arg.type_schema) && !input.starts_with('"') {
+                    serde_json::Value::String(input)
+                } else {
+                    match serde_json::from_str(&input) {
+                        Ok(value) => value,
+                        Err(err) => {
+                            println!("The value is not valid JSON: {}", err);
+                            continue;
+                        }
+                    }
+                };
+                match validate(&value, &arg.type_schema, &self.body.root_schema) {
+                    Ok(()) => break value,
+                    Err(err) => println!("{}", err),
+                }
+            };
+            object.insert(arg.name.clone(), value);
+        }
+        Ok(serde_json::Value::Object(object).to_string())
+    }
+
+    /// Checks the JSON args of the method against the argument types declared in the ABI
+    pub fn validate_args(&self, function: &AbiFunction, args: &[u8]) -> crate::CliResult {
+        let abi_args = match &function.params {
+            AbiParameters::Json { args } => args,
+            AbiParameters::Borsh { .. } => return Ok(()),
+        };
+        let args: serde_json::Value = if args.is_empty() && abi_args.is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::from_slice(args).map_err(|err| {
+                color_eyre::Report::msg(format!("The args are not valid JSON: {}", err))
+            })?
+        };
+        let args = args
+            .as_object()
+            .ok_or_else(|| color_eyre::Report::msg("The args must be a JSON object"))?;
+        if let Some(name) = args
+            .keys()
+            .find(|name| abi_args.iter().all(|abi_arg| &abi_arg.name != *name))
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "The method <{}> has no argument <{}>",
+                function.name, name
+            )));
+        }
+        for abi_arg in abi_args {
+            let value = args.get(&abi_arg.name).unwrap_or(&serde_json::Value::Null);
+            validate(value, &abi_arg.type_schema, &self.body.root_schema).map_err(|err| {
+                color_eyre::Report::msg(format!("Invalid argument <{}>: {}", abi_arg.name, err))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn describe_function(&self, function: &AbiFunction) -> String {
+        let args = match &function.params {
+            AbiParameters::Json { args } => args
+                .iter()
+                .map(|arg| format!("{}: {}", arg.name, self.type_name(&arg.type_schema)))
+                .collect::<Vec<String>>()
+                .join(", "),
+            AbiParameters::Borsh { .. } => "borsh".to_string(),
+        };
+        if function.modifiers.is_empty() {
+            format!("{}({})", function.name, args)
+        } else {
+            format!(
+                "{}({}) [{}]",
+                function.name,
+                args,
+                function.modifiers.join(", ")
+            )
+        }
+    }
+
+    fn type_name(&self, schema: &serde_json::Value) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(|reference| reference.as_str()) {
+            return reference
+                .rsplit('/')
+                .next()
+                .unwrap_or(reference)
+                .to_string();
+        }
+        match schema.get("type") {
+            Some(serde_json::Value::String(schema_type)) => schema_type.clone(),
+            Some(serde_json::Value::Array(schema_types)) => schema_types
+                .iter()
+                .filter_map(|schema_type| schema_type.as_str())
+                .collect::<Vec<&str>>()
+                .join(" | "),
+            _ => "json".to_string(),
+        }
+    }
+
+    fn is_string_type(&self, schema: &serde_json::Value) -> bool {
+        match resolve(schema, &self.body.root_schema)
+            .ok()
+            .and_then(|schema| schema.get("type"))
+        {
+            Some(serde_json::Value::String(schema_type)) => schema_type == "string",
+            _ => false,
+        }
+    }
+}
+
+/// Reads the ABI from the file or, if no file is given, the ABI embedded in the contract
+/// (`__contract_abi`). Returns None if the contract does not embed an ABI, any other failure to
+/// fetch it is an error.
+pub async fn get_contract_abi(
+    connection_config: Option<&crate::common::ConnectionConfig>,
+    contract_account_id: near_primitives::types::AccountId,
+    abi_file: Option<&std::path::Path>,
+) -> color_eyre::eyre::Result<Option<AbiRoot>> {
+    if let Some(abi_file) = abi_file {
+        let abi = std::fs::read(abi_file).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to open or read the ABI file: {:?}", err))
+        })?;
+        return Ok(Some(serde_json::from_slice(&abi).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to parse the ABI file: {}", err))
+        })?));
+    }
+    let connection_config = match connection_config {
+        Some(connection_config) => connection_config,
+        None => return Ok(None),
+    };
+    let compressed_abi = match crate::common::call_view_function_if_exists(
+        connection_config,
+        contract_account_id,
+        "__contract_abi",
+        vec![],
+    )
+    .await?
+    {
+        Some(compressed_abi) => compressed_abi,
+        None => return Ok(None),
+    };
+    let mut source = compressed_abi.as_slice();
+    let mut decoder = ruzstd::StreamingDecoder::new(&mut source).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to decompress the contract ABI: {}", err))
+    })?;
+    let mut abi = vec![];
+    decoder.read_to_end(&mut abi)?;
+    Ok(Some(serde_json::from_slice(&abi).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to parse the contract ABI: {}", err))
+    })?))
+}
+
+/// The ABI for the prompts and the argument checks. A broken ABI file given by the user is an
+/// error, but the method can still be called without the ABI embedded in the contract, so a
+/// failure to fetch or parse that one is only a warning.
+pub fn load_contract_abi(
+    connection_config: Option<&crate::common::ConnectionConfig>,
+    contract_account_id: near_primitives::types::AccountId,
+    abi_file: Option<&std::path::Path>,
+) -> color_eyre::eyre::Result<Option<AbiRoot>> {
+    let abi = actix::System::new().block_on(get_contract_abi(
+        connection_config,
+        contract_account_id,
+        abi_file,
+    ));
+    match abi {
+        Err(err) if abi_file.is_none() => {
+            println!(
+                "WARNING! The contract ABI is not used, the method is called without it: {}",
+                err
+            );
+            Ok(None)
+        }
+        abi => abi,
+    }
+}
+
+/// Prints the result of a view method decoded with the return type declared in the ABI
+pub fn display_call_result(
+    call_result: Vec<u8>,
+    abi: Option<&AbiRoot>,
+    method_name: &str,
+) -> crate::CliResult {
+    let result_type = abi.and_then(|abi| {
+        abi.function(method_name)
+            .and_then(|function| function.result.as_ref())
+            .map(|result_type| (abi, result_type))
+    });
+    let serde_call_result: serde_json::Value = match result_type {
+        Some((_, AbiType::Borsh { type_schema })) => {
+            let borsh_schema: Option<crate::types::state_format::BorshSchema> = type_schema
+                .get("declaration")
+                .and_then(|declaration| declaration.as_str())
+                .and_then(|declaration| declaration.to_lowercase().parse().ok());
+            match borsh_schema.map(|borsh_schema| borsh_schema.decode(&call_result)) {
+                Some(Ok(value)) => value,
+                _ => {
+                    println!("--------------");
+                    println!();
+                    println!(
+                        "The result is Borsh-serialized (base64): {}",
+                        base64::encode(&call_result)
+                    );
+                    return Ok(());
+                }
+            }
+        }
+        Some((abi, AbiType::Json { type_schema })) => {
+            let value = serde_json::from_slice(&call_result)
+                .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
+            if let Err(err) = validate(&value, type_schema, &abi.body.root_schema) {
+                println!(
+                    "The result does not match the type declared in the ABI: {}",
+                    err
+                );
+            }
+            value
+        }
+        None => {
+            let call_result_str = String::from_utf8(call_result)?;
+            serde_json::from_str(&call_result_str)
+                .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?
+        }
+    };
+    println!("--------------");
+    println!();
+    println!("{}", serde_json::to_string_pretty(&serde_call_result)?);
+    Ok(())
+}
+
+/// Follows the `$ref` chain of the schema to its definition.
+/// A chain which comes back to a definition it has already passed is an error.
+fn resolve<'a>(
+    schema: &'a serde_json::Value,
+    root_schema: &'a serde_json::Value,
+) -> Result<&'a serde_json::Value, String> {
+    resolve_definition(schema, root_schema, &mut vec![])
+}
+
+/// Like `resolve`, but the definitions passed before are given, so a cycle which goes through
+/// `allOf`, `anyOf` or `oneOf` is found as well
+fn resolve_definition<'a>(
+    schema: &'a serde_json::Value,
+    root_schema: &'a serde_json::Value,
+    visited: &mut Vec<&'a str>,
+) -> Result<&'a serde_json::Value, String> {
+    let mut schema = schema;
+    while let Some(name) = schema
+        .get("$ref")
+        .and_then(|reference| reference.as_str())
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+    {
+        if visited.contains(&name) {
+            return Err(format!("the schema definition <{}> refers to itself", name));
+        }
+        visited.push(name);
+        match root_schema
+            .get("definitions")
+            .and_then(|definitions| definitions.get(name))
+        {
+            Some(definition) => schema = definition,
+            None => break,
+        }
+    }
+    Ok(schema)
+}
+
+/// Validates the value against the subset of JSON Schema used by NEAR ABI
+fn validate(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    root_schema: &serde_json::Value,
+) -> Result<(), String> {
+    validate_schema(value, schema, root_schema, &mut vec![])
+}
+
+/// `visited` holds the definitions passed since the last step into the value: passing one of
+/// them again without going deeper into the value would never end
+fn validate_schema<'a>(
+    value: &serde_json::Value,
+    schema: &'a serde_json::Value,
+    root_schema: &'a serde_json::Value,
+    visited: &mut Vec<&'a str>,
+) -> Result<(), String> {
+    let schema = resolve_definition(schema, root_schema, visited)?;
+    if let Some(schema) = schema.as_bool() {
+        return if schema {
+            Ok(())
+        } else {
+            Err(format!("{} is not allowed", value))
+        };
+    }
+    for keyword in &["anyOf", "oneOf"] {
+        if let Some(schemas) = schema.get(*keyword).and_then(|schemas| schemas.as_array()) {
+            if !schemas.iter().any(|schema| {
+                validate_schema(value, schema, root_schema, &mut visited.clone()).is_ok()
+            }) {
+                return Err(format!("{} does not match any of the allowed types", value));
+            }
+        }
+    }
+    if let Some(schemas) = schema.get("allOf").and_then(|schemas| schemas.as_array()) {
+        for schema in schemas {
+            validate_schema(value, schema, root_schema, &mut visited.clone())?;
+        }
+    }
+    if let Some(variants) = schema.get("enum").and_then(|variants| variants.as_array()) {
+        if !variants.contains(value) {
+            return Err(format!(
+                "{} is not one of {}",
+                value,
+                serde_json::Value::Array(variants.clone())
+            ));
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return Err(format!("{} is not {}", value, constant));
+        }
+    }
+    let types: Vec<&str> = match schema.get("type") {
+        Some(serde_json::Value::String(schema_type)) => vec![schema_type.as_str()],
+        Some(serde_json::Value::Array(schema_types)) => schema_types
+            .iter()
+            .filter_map(|schema_type| schema_type.as_str())
+            .collect(),
+        _ => vec![],
+    };
+    if !types.is_empty() && !types.iter().any(|schema_type| has_type(value, schema_type)) {
+        return Err(format!("{} is not of type {}", value, types.join(" | ")));
+    }
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(|minimum| minimum.as_f64()) {
+            if number < minimum {
+                return Err(format!("{} is less than the minimum {}", value, minimum));
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(|maximum| maximum.as_f64()) {
+            if number > maximum {
+                return Err(format!("{} is greater than the maximum {}", value, maximum));
+            }
+        }
+    }
+    if let Some(object) = value.as_object() {
+        if let Some(required) = schema
+            .get("required")
+            .and_then(|required| required.as_array())
+        {
+            for name in required.iter().filter_map(|name| name.as_str()) {
+                if !object.contains_key(name) {
+                    return Err(format!("the field <{}> is missing", name));
+                }
+            }
+        }
+        let properties = schema
+            .get("properties")
+            .and_then(|properties| properties.as_object());
+        for (name, field) in object {
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(field_schema) => validate(field, field_schema, root_schema)
+                    .map_err(|err| format!("<{}>: {}", name, err))?,
+                None => {
+                    if let Some(additional_properties) = schema.get("additionalProperties") {
+                        validate(field, additional_properties, root_schema)
+                            .map_err(|err| format!("<{}>: {}", name, err))?
+                    }
+                }
+            }
+        }
+    }
+    if let Some(items) = value.as_array() {
+        match schema.get("items") {
+            Some(serde_json::Value::Array(item_schemas)) => {
+                if items.len() != item_schemas.len() {
+                    return Err(format!("{} must have {} items", value, item_schemas.len()));
+                }
+                for (item, item_schema) in items.iter().zip(item_schemas) {
+                    validate(item, item_schema, root_schema)?;
+                }
+            }
+            Some(item_schema) => {
+                for item in items {
+                    validate(item, item_schema, root_schema)?;
+                }
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+fn has_type(value: &serde_json::Value, schema_type: &str) -> bool {
+    match schema_type {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().map_or(false, |number| number.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_args_with_abi_schema() {
+        let root_schema = serde_json::json!({
+            "definitions": {
+                "AccountId": {"type": "string"},
+                "U128": {"type": "string"},
+                "Transfer": {
+                    "type": "object",
+                    "required": ["receiver_id", "amount"],
+                    "properties": {
+                        "receiver_id": {"$ref": "#/definitions/AccountId"},
+                        "amount": {"$ref": "#/definitions/U128"},
+                        "memo": {"type": ["string", "null"]}
+                    }
+                }
+            }
+        });
+        let schema = serde_json::json!({"$ref": "#/definitions/Transfer"});
+        assert!(validate(
+            &serde_json::json!({"receiver_id": "bob.near", "amount": "10"}),
+            &schema,
+            &root_schema
+        )
+        .is_ok());
+        assert!(validate(
+            &serde_json::json!({"receiver_id": "bob.near", "amount": 10}),
+            &schema,
+            &root_schema
+        )
+        .is_err());
+        assert!(validate(
+            &serde_json::json!({"receiver_id": "bob.near"}),
+            &schema,
+            &root_schema
+        )
+        .is_err());
+        let schema = serde_json::json!({"type": "integer", "format": "uint8", "minimum": 0.0});
+        assert!(validate(&serde_json::json!(5), &schema, &root_schema).is_ok());
+        assert!(validate(&serde_json::json!(-1), &schema, &root_schema).is_err());
+        assert!(validate(&serde_json::json!(1.5), &schema, &root_schema).is_err());
+    }
+    #[test]
+    fn reject_cyclic_schema_reference() {
+        let root_schema = serde_json::json!({
+            "definitions": {
+                "A": {"$ref": "#/definitions/B"},
+                "B": {"$ref": "#/definitions/A"},
+                "Node": {
+                    "type": "object",
+                    "properties": {"next": {"anyOf": [{"$ref": "#/definitions/Node"}, {"type": "null"}]}}
+                }
+            }
+        });
+        let schema = serde_json::json!({"$ref": "#/definitions/A"});
+        assert!(resolve(&schema, &root_schema).is_err());
+        assert!(validate(&serde_json::json!(1), &schema, &root_schema).is_err());
+        // A recursive type is not a cycle as long as every step goes into the value
+        let schema = serde_json::json!({"$ref": "#/definitions/Node"});
+        assert!(validate(
+            &serde_json::json!({"next": {"next": null}}),
+            &schema,
+            &root_schema
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn reject_cyclic_schema_through_combinators() {
+        let root_schema = serde_json::json!({
+            "definitions": {
+                "A": {"allOf": [{"$ref": "#/definitions/A"}]},
+                "B": {"anyOf": [{"$ref": "#/definitions/C"}]},
+                "C": {"oneOf": [{"$ref": "#/definitions/B"}]},
+                "D": {"allOf": [{"$ref": "#/definitions/E"}, {"$ref": "#/definitions/E"}]},
+                "E": {"type": "integer"}
+            }
+        });
+        let schema = serde_json::json!({"$ref": "#/definitions/A"});
+        assert!(validate(&serde_json::json!(1), &schema, &root_schema).is_err());
+        let schema = serde_json::json!({"$ref": "#/definitions/B"});
+        assert!(validate(&serde_json::json!(1), &schema, &root_schema).is_err());
+        // The same definition in two branches is not a cycle
+        let schema = serde_json::json!({"$ref": "#/definitions/D"});
+        assert!(validate(&serde_json::json!(1), &schema, &root_schema).is_ok());
+    }
+}
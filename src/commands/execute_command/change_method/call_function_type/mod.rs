@@ -1,7 +1,8 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = super::operation_mode::ExecuteChangeMethodCommandNetworkContext)]
+#[interactive_clap(context = super::ExecuteChangeMethodContractContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct CallFunctionAction {
     method_name: String,
     args: String,
@@ -9,23 +10,107 @@ pub struct CallFunctionAction {
     gas: crate::common::NearGas,
     #[interactive_clap(long = "attached-deposit")]
     deposit: crate::common::NearBalance,
+    ///Read the contract ABI from this file instead of the ABI embedded in the contract
+    #[interactive_clap(long)]
+    abi_file: Option<crate::types::path_buf::PathBuf>,
+    ///Do not check the args against the argument types declared in the ABI (true or false)
+    #[interactive_clap(long)]
+    skip_abi_validation: Option<bool>,
+    #[interactive_clap(skip)]
+    abi: Option<super::super::abi::AbiRoot>,
     #[interactive_clap(named_arg)]
     /// Specify a signer
     signer: super::signer::Sender,
 }
 
+impl CallFunctionAction {
+    pub fn from_cli(
+        optional_clap_variant: Option<<CallFunctionAction as interactive_clap::ToCli>::CliVariant>,
+        context: super::ExecuteChangeMethodContractContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let abi_file = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.abi_file);
+        let optional_method_name = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.method_name);
+        let optional_args = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.args);
+        let skip_abi_validation = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.skip_abi_validation);
+        // The embedded ABI is only fetched for the prompts, so the calls with all the arguments
+        // given do not depend on it
+        let abi = if abi_file.is_some() || optional_method_name.is_none() || optional_args.is_none()
+        {
+            super::super::abi::load_contract_abi(
+                context.connection_config.as_ref(),
+                context.contract_account_id.clone().into(),
+                abi_file.as_ref().map(|abi_file| abi_file.0.as_path()),
+            )?
+        } else {
+            None
+        };
+        let method_name = match optional_method_name {
+            Some(method_name) => method_name,
+            None => Self::input_method_name(&context, abi.as_ref())?,
+        };
+        let args = match optional_args {
+            Some(args) => args,
+            None => Self::input_args(&context, abi.as_ref(), &method_name)?,
+        };
+        let gas = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.gas)
+        {
+            Some(gas) => gas,
+            None => Self::input_gas(&context)?,
+        };
+        let deposit = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.deposit)
+        {
+            Some(deposit) => deposit,
+            None => Self::input_deposit(&context)?,
+        };
+        let signer = super::signer::Sender::from_cli(
+            optional_clap_variant.and_then(|clap_variant| match clap_variant.signer {
+                Some(ClapNamedArgSenderForCallFunctionAction::Signer(cli_args)) => Some(cli_args),
+                None => None,
+            }),
+            context,
+        )?;
+        Ok(Self {
+            method_name,
+            args,
+            gas,
+            deposit,
+            abi_file,
+            skip_abi_validation,
+            abi,
+            signer,
+        })
+    }
+}
+
 impl CallFunctionAction {
     fn input_method_name(
-        _context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        context: &super::ExecuteChangeMethodContractContext,
+        abi: Option<&super::super::abi::AbiRoot>,
     ) -> color_eyre::eyre::Result<String> {
         println!();
-        Ok(Input::new()
-            .with_prompt("Enter a method name")
-            .interact_text()?)
+        match abi {
+            Some(abi) => abi.input_method_name(super::super::abi::AbiFunctionKind::Call),
+            None => crate::common::input_method_name(
+                context.connection_config.as_ref(),
+                context.contract_account_id.clone().into(),
+            ),
+        }
     }
 
     fn input_gas(
-        _context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        _context: &super::ExecuteChangeMethodContractContext,
     ) -> color_eyre::eyre::Result<crate::common::NearGas> {
         println!();
         let gas: u64 = loop {
@@ -46,16 +131,23 @@ impl CallFunctionAction {
     }
 
     fn input_args(
-        _context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        _context: &super::ExecuteChangeMethodContractContext,
+        abi: Option<&super::super::abi::AbiRoot>,
+        method_name: &str,
     ) -> color_eyre::eyre::Result<String> {
         println!();
+        if let Some((abi, function)) =
+            abi.and_then(|abi| abi.function(method_name).map(|function| (abi, function)))
+        {
+            return abi.input_args(function);
+        }
         Ok(Input::new()
             .with_prompt("Enter args for function")
             .interact_text()?)
     }
 
     fn input_deposit(
-        _context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        _context: &super::ExecuteChangeMethodContractContext,
     ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
         println!();
         let deposit: crate::common::NearBalance = Input::new()
@@ -71,6 +163,20 @@ impl CallFunctionAction {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some((abi, function)) = self.abi.as_ref().and_then(|abi| {
+            abi.function(&self.method_name)
+                .map(|function| (abi, function))
+        }) {
+            if function.kind == super::super::abi::AbiFunctionKind::View {
+                println!(
+                    "The method <{}> is a view method, calling it in a transaction does not change the contract state",
+                    self.method_name
+                );
+            }
+            if !self.skip_abi_validation.unwrap_or(false) {
+                abi.validate_args(function, self.args.as_bytes())?;
+            }
+        }
         let action = near_primitives::transaction::Action::FunctionCall(
             near_primitives::transaction::FunctionCallAction {
                 method_name: self.method_name.clone(),
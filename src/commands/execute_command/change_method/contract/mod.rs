@@ -1,51 +1,63 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = super::operation_mode::ExecuteCommandNetworkContext)]
-#[interactive_clap(skip_default_from_cli)]
+#[interactive_clap(input_context = super::operation_mode::ExecuteChangeMethodCommandNetworkContext)]
+#[interactive_clap(output_context = super::ExecuteChangeMethodContractContext)]
 pub struct Contract {
+    #[interactive_clap(skip_default_from_cli)]
     pub contract_account_id: crate::types::account_id::AccountId,
     #[interactive_clap(named_arg)]
     /// calling a change method
     pub call: super::call_function_type::CallFunctionAction,
 }
 
+struct ContractContext {
+    connection_config: Option<crate::common::ConnectionConfig>,
+    contract_account_id: crate::types::account_id::AccountId,
+}
+
+impl ContractContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        scope: &<Contract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            contract_account_id: scope.contract_account_id.clone(),
+        }
+    }
+}
+
+impl From<ContractContext> for super::ExecuteChangeMethodContractContext {
+    fn from(item: ContractContext) -> Self {
+        Self {
+            connection_config: item.connection_config,
+            contract_account_id: item.contract_account_id,
+        }
+    }
+}
+
 impl Contract {
-    pub fn from_cli(
-        optional_clap_variant: Option<<Contract as interactive_clap::ToCli>::CliVariant>,
-        context: super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
-    ) -> color_eyre::eyre::Result<Self> {
-        let connection_config = context.connection_config.clone();
-        let contract_account_id = match optional_clap_variant
-            .clone()
-            .and_then(|clap_variant| clap_variant.contract_account_id)
-        {
-            Some(contract_account_id) => match &connection_config {
+    fn from_cli_contract_account_id(
+        optional_cli_contract_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_contract_account_id {
+            Some(cli_contract_account_id) => match &context.connection_config {
                 Some(network_connection_config) => match crate::common::get_account_state(
                     &network_connection_config,
-                    contract_account_id.clone().into(),
+                    cli_contract_account_id.clone().into(),
                 )? {
-                    Some(_) => contract_account_id,
+                    Some(_) => Ok(cli_contract_account_id),
                     None => {
-                        println!("Account <{}> doesn't exist", contract_account_id);
-                        Self::input_contract_account_id(&context)?
+                        println!("Account <{}> doesn't exist", cli_contract_account_id);
+                        Self::input_contract_account_id(&context)
                     }
                 },
-                None => contract_account_id,
+                None => Ok(cli_contract_account_id),
             },
-            None => Self::input_contract_account_id(&context)?,
-        };
-        let call = super::call_function_type::CallFunctionAction::from_cli(
-            optional_clap_variant.and_then(|clap_variant| match clap_variant.call {
-                Some(ClapNamedArgCallFunctionActionForContract::Call(cli_args)) => Some(cli_args),
-                None => None,
-            }),
-            context,
-        )?;
-        Ok(Self {
-            contract_account_id,
-            call,
-        })
+            None => Self::input_contract_account_id(&context),
+        }
     }
 }
 
@@ -2,3 +2,8 @@ mod call_function_type;
 mod contract;
 pub mod operation_mode;
 mod signer;
+
+pub struct ExecuteChangeMethodContractContext {
+    pub connection_config: Option<crate::common::ConnectionConfig>,
+    pub contract_account_id: crate::types::account_id::AccountId,
+}
@@ -1,7 +1,7 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(input_context = super::operation_mode::ExecuteChangeMethodCommandNetworkContext)]
+#[interactive_clap(input_context = super::ExecuteChangeMethodContractContext)]
 #[interactive_clap(output_context = crate::common::SignerContext)]
 pub struct Sender {
     #[interactive_clap(skip_default_from_cli)]
@@ -18,7 +18,7 @@ struct SenderContext {
 
 impl SenderContext {
     pub fn from_previous_context(
-        previous_context: super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        previous_context: super::ExecuteChangeMethodContractContext,
         scope: &<Sender as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> Self {
         Self {
@@ -40,7 +40,7 @@ impl From<SenderContext> for crate::common::SignerContext {
 impl Sender {
     fn from_cli_sender_account_id(
         optional_cli_sender_account_id: Option<crate::types::account_id::AccountId>,
-        context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        context: &super::ExecuteChangeMethodContractContext,
     ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
         match optional_cli_sender_account_id {
             Some(cli_sender_account_id) => match &context.connection_config {
@@ -61,7 +61,7 @@ impl Sender {
     }
 
     fn input_sender_account_id(
-        context: &super::operation_mode::ExecuteChangeMethodCommandNetworkContext,
+        context: &super::ExecuteChangeMethodContractContext,
     ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
         loop {
             let account_id: crate::types::account_id::AccountId = Input::new()
@@ -1,14 +1,14 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = super::super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext)]
+#[interactive_clap(context = super::super::ExecuteViewMethodContractContext)]
 pub struct BlockIdHeight {
     block_id_height: near_primitives::types::BlockHeight,
 }
 
 impl BlockIdHeight {
     pub fn input_block_id_height(
-        _context: &super::super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext,
+        _context: &super::super::ExecuteViewMethodContractContext,
     ) -> color_eyre::eyre::Result<near_primitives::types::BlockHeight> {
         Ok(Input::new()
             .with_prompt("Type the block ID height for this contract")
@@ -21,6 +21,7 @@ impl BlockIdHeight {
         contract_account_id: near_primitives::types::AccountId,
         method_name: String,
         args: Vec<u8>,
+        abi: Option<super::super::super::abi::AbiRoot>,
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
@@ -33,7 +34,7 @@ impl BlockIdHeight {
             ),
             request: near_primitives::views::QueryRequest::CallFunction {
                 account_id: contract_account_id,
-                method_name,
+                method_name: method_name.clone(),
                 args,
             },
         })
@@ -49,12 +50,6 @@ impl BlockIdHeight {
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
-        let call_result_str = String::from_utf8(call_result)?;
-        let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
-            .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
-        println!("--------------");
-        println!();
-        println!("{}", serde_json::to_string_pretty(&serde_call_result)?);
-        Ok(())
+        super::super::super::abi::display_call_result(call_result, abi.as_ref(), &method_name)
     }
 }
@@ -5,7 +5,7 @@ mod block_id_height;
 
 #[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
-#[interactive_clap(context = super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext)]
+#[interactive_clap(context = super::ExecuteViewMethodContractContext)]
 ///Choose Block ID
 pub enum BlockId {
     #[strum_discriminants(strum(message = "View this contract at final block"))]
@@ -26,6 +26,7 @@ impl BlockId {
         network_connection_config: crate::common::ConnectionConfig,
         method_name: String,
         args: Vec<u8>,
+        abi: Option<super::super::abi::AbiRoot>,
     ) -> crate::CliResult {
         println!();
         match self {
@@ -36,6 +37,7 @@ impl BlockId {
                         contract_account_id,
                         method_name,
                         args,
+                        abi,
                     )
                     .await
             }
@@ -46,6 +48,7 @@ impl BlockId {
                         contract_account_id,
                         method_name,
                         args,
+                        abi,
                     )
                     .await
             }
@@ -55,6 +58,7 @@ impl BlockId {
                     contract_account_id,
                     method_name,
                     args,
+                    abi,
                 )
                 .await
             }
@@ -67,6 +71,7 @@ impl BlockId {
         contract_account_id: near_primitives::types::AccountId,
         method_name: String,
         args: Vec<u8>,
+        abi: Option<super::super::abi::AbiRoot>,
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
@@ -77,7 +82,7 @@ impl BlockId {
             block_reference: near_primitives::types::Finality::Final.into(),
             request: near_primitives::views::QueryRequest::CallFunction {
                 account_id: contract_account_id,
-                method_name,
+                method_name: method_name.clone(),
                 args,
             },
         })
@@ -93,12 +98,6 @@ impl BlockId {
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
-        let call_result_str = String::from_utf8(call_result)?;
-        let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
-            .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
-        println!("--------------");
-        println!();
-        println!("{}", serde_json::to_string_pretty(&serde_call_result)?);
-        Ok(())
+        super::super::abi::display_call_result(call_result, abi.as_ref(), &method_name)
     }
 }
@@ -1,28 +1,105 @@
 use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
-#[interactive_clap(context = super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext)]
+#[interactive_clap(context = super::ExecuteViewMethodContractContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct CallFunctionView {
     method_name: String,
     function_args: String,
+    ///Read the contract ABI from this file instead of the ABI embedded in the contract
+    #[interactive_clap(long)]
+    abi_file: Option<crate::types::path_buf::PathBuf>,
+    ///Do not check the args against the argument types declared in the ABI (true or false)
+    #[interactive_clap(long)]
+    skip_abi_validation: Option<bool>,
+    #[interactive_clap(skip)]
+    abi: Option<super::super::abi::AbiRoot>,
     #[interactive_clap(subcommand)]
     selected_block_id: super::block_id::BlockId,
 }
 
+impl CallFunctionView {
+    pub fn from_cli(
+        optional_clap_variant: Option<<CallFunctionView as interactive_clap::ToCli>::CliVariant>,
+        context: super::ExecuteViewMethodContractContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let abi_file = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.abi_file);
+        let optional_method_name = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.method_name);
+        let optional_function_args = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.function_args);
+        let skip_abi_validation = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.skip_abi_validation);
+        // The embedded ABI is only fetched for the prompts, so the calls with all the arguments
+        // given do not depend on it
+        let abi = if abi_file.is_some()
+            || optional_method_name.is_none()
+            || optional_function_args.is_none()
+        {
+            super::super::abi::load_contract_abi(
+                Some(&context.connection_config),
+                context.contract_account_id.clone().into(),
+                abi_file.as_ref().map(|abi_file| abi_file.0.as_path()),
+            )?
+        } else {
+            None
+        };
+        let method_name = match optional_method_name {
+            Some(method_name) => method_name,
+            None => Self::input_method_name(&context, abi.as_ref())?,
+        };
+        let function_args = match optional_function_args {
+            Some(function_args) => function_args,
+            None => Self::input_function_args(&context, abi.as_ref(), &method_name)?,
+        };
+        let selected_block_id: super::block_id::BlockId = match optional_clap_variant
+            .and_then(|clap_variant| clap_variant.selected_block_id)
+        {
+            Some(cli_block_id) => super::block_id::BlockId::from_cli(Some(cli_block_id), context)?,
+            None => super::block_id::BlockId::choose_variant(context)?,
+        };
+        Ok(Self {
+            method_name,
+            function_args,
+            abi_file,
+            skip_abi_validation,
+            abi,
+            selected_block_id,
+        })
+    }
+}
+
 impl CallFunctionView {
     fn input_method_name(
-        _context: &super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext,
+        context: &super::ExecuteViewMethodContractContext,
+        abi: Option<&super::super::abi::AbiRoot>,
     ) -> color_eyre::eyre::Result<String> {
         println!();
-        Ok(Input::new()
-            .with_prompt("Enter a method name")
-            .interact_text()?)
+        match abi {
+            Some(abi) => abi.input_method_name(super::super::abi::AbiFunctionKind::View),
+            None => crate::common::input_method_name(
+                Some(&context.connection_config),
+                context.contract_account_id.clone().into(),
+            ),
+        }
     }
 
     fn input_function_args(
-        _context: &super::operation_mode::online_mode::select_server::ExecuteViewMethodCommandNetworkContext,
+        _context: &super::ExecuteViewMethodContractContext,
+        abi: Option<&super::super::abi::AbiRoot>,
+        method_name: &str,
     ) -> color_eyre::eyre::Result<String> {
         println!();
+        if let Some((abi, function)) =
+            abi.and_then(|abi| abi.function(method_name).map(|function| (abi, function)))
+        {
+            return abi.input_args(function);
+        }
         Ok(Input::new()
             .with_prompt("Enter args for function")
             .interact_text()?)
@@ -33,12 +110,22 @@ impl CallFunctionView {
         network_connection_config: crate::common::ConnectionConfig,
         contract_account_id: near_primitives::types::AccountId,
     ) -> crate::CliResult {
+        let args = self.function_args.into_bytes();
+        if let Some((abi, function)) = self.abi.as_ref().and_then(|abi| {
+            abi.function(&self.method_name)
+                .map(|function| (abi, function))
+        }) {
+            if !self.skip_abi_validation.unwrap_or(false) {
+                abi.validate_args(function, &args)?;
+            }
+        }
         self.selected_block_id
             .process(
                 contract_account_id,
                 network_connection_config,
                 self.method_name,
-                self.function_args.into_bytes(),
+                args,
+                self.abi,
             )
             .await
     }
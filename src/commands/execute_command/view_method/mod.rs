@@ -2,3 +2,9 @@ mod block_id;
 mod call_function_type;
 mod contract;
 pub mod operation_mode;
+
+#[derive(Clone)]
+pub struct ExecuteViewMethodContractContext {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub contract_account_id: crate::types::account_id::AccountId,
+}
@@ -0,0 +1,80 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::LoginCommandNetworkContext)]
+///Select the permission of the access key to request from the wallet
+pub enum AccessKeyMode {
+    #[strum_discriminants(strum(message = "Full access key"))]
+    ///Request a full access key
+    FullAccess,
+    #[strum_discriminants(strum(message = "Function-call access key limited to a contract"))]
+    ///Request a function-call access key limited to a contract and, optionally, its methods
+    FunctionCall(FunctionCallAccessKey),
+}
+
+impl AccessKeyMode {
+    pub fn function_call_permission(
+        &self,
+    ) -> Option<near_primitives::account::FunctionCallPermission> {
+        match self {
+            Self::FullAccess => None,
+            Self::FunctionCall(function_call_access_key) => {
+                Some(near_primitives::account::FunctionCallPermission {
+                    allowance: None,
+                    receiver_id: function_call_access_key.contract_account_id.to_string(),
+                    method_names: function_call_access_key
+                        .method_names
+                        .0
+                        .iter()
+                        .filter(|method_name| !method_name.is_empty())
+                        .cloned()
+                        .collect(),
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::LoginCommandNetworkContext)]
+pub struct FunctionCallAccessKey {
+    pub contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    pub method_names: crate::types::vec_string::VecString,
+}
+
+impl FunctionCallAccessKey {
+    fn input_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::LoginCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("Which contract should the access key be limited to?")
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            }
+        }
+    }
+
+    fn input_method_names(
+        _context: &super::operation_mode::online_mode::select_server::LoginCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::vec_string::VecString> {
+        let input_method_names: String = Input::new()
+            .with_prompt("Enter a comma-separated list of method names the access key may call (leave empty to allow any method)")
+            .allow_empty(true)
+            .interact_text()?;
+        if input_method_names.trim().is_empty() {
+            Ok(crate::types::vec_string::VecString(vec![]))
+        } else {
+            input_method_names.parse()
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use actix_web::{web, App, HttpResponse, HttpServer};
+
+/// How long to wait for the wallet to redirect back before falling back to manual entry
+const CALLBACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+const CALLBACK_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+#[derive(Debug)]
+pub enum WalletCallback {
+    Success {
+        account_id: String,
+        public_key: String,
+    },
+    Failure {
+        error_code: String,
+    },
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SuccessQuery {
+    account_id: String,
+    public_key: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct FailureQuery {
+    #[serde(rename = "errorCode")]
+    error_code: Option<String>,
+}
+
+type CallbackSender = std::sync::Mutex<std::sync::mpsc::Sender<WalletCallback>>;
+
+/// A temporary localhost HTTP server the wallet redirects to after the login
+pub struct CallbackServer {
+    server: actix_web::dev::Server,
+    address: std::net::SocketAddr,
+    receiver: std::sync::mpsc::Receiver<WalletCallback>,
+}
+
+impl CallbackServer {
+    pub fn start() -> color_eyre::eyre::Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender: web::Data<CallbackSender> = web::Data::new(std::sync::Mutex::new(sender));
+        let http_server = HttpServer::new(move || {
+            App::new()
+                .app_data(sender.clone())
+                .route("/success", web::get().to(success))
+                .route("/failure", web::get().to(failure))
+        })
+        .workers(1)
+        .bind("127.0.0.1:0")?;
+        let address = *http_server.addrs().first().ok_or_else(|| {
+            color_eyre::Report::msg("Failed to bind the login callback server".to_string())
+        })?;
+        Ok(Self {
+            server: http_server.run(),
+            address,
+            receiver,
+        })
+    }
+
+    pub fn success_url(&self) -> String {
+        format!("http://{}/success", self.address)
+    }
+
+    pub fn failure_url(&self) -> String {
+        format!("http://{}/failure", self.address)
+    }
+
+    /// Waits for the wallet redirect and shuts the server down; returns `None` on timeout
+    pub async fn wait_for_callback(self) -> Option<WalletCallback> {
+        let started_at = std::time::Instant::now();
+        let callback = loop {
+            match self.receiver.try_recv() {
+                Ok(callback) => break Some(callback),
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    if started_at.elapsed() > CALLBACK_TIMEOUT {
+                        break None;
+                    }
+                    actix_web::rt::time::sleep(CALLBACK_POLL_INTERVAL).await;
+                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => break None,
+            }
+        };
+        self.server.stop(true).await;
+        callback
+    }
+}
+
+async fn success(
+    query: web::Query<SuccessQuery>,
+    sender: web::Data<CallbackSender>,
+) -> HttpResponse {
+    let query = query.into_inner();
+    send_callback(
+        &sender,
+        WalletCallback::Success {
+            account_id: query.account_id,
+            public_key: query.public_key,
+        },
+    );
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body("<h3>NEAR CLI: you have successfully logged in.</h3><p>You can close this page and return to the terminal.</p>")
+}
+
+async fn failure(
+    query: web::Query<FailureQuery>,
+    sender: web::Data<CallbackSender>,
+) -> HttpResponse {
+    send_callback(
+        &sender,
+        WalletCallback::Failure {
+            error_code: query
+                .into_inner()
+                .error_code
+                .unwrap_or_else(|| "unknown".to_string()),
+        },
+    );
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body("<h3>NEAR CLI: the login was not completed.</h3><p>You can close this page and return to the terminal.</p>")
+}
+
+fn send_callback(sender: &CallbackSender, callback: WalletCallback) {
+    if let Ok(sender) = sender.lock() {
+        sender.send(callback).ok();
+    }
+}
@@ -1 +1,3 @@
+mod access_key_mode;
+mod callback_server;
 pub mod operation_mode;
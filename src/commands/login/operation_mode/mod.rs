@@ -2,14 +2,57 @@ pub mod online_mode;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = ())]
+#[interactive_clap(skip_default_from_cli)]
 pub struct OperationMode {
+    ///The seed phrase to derive the key pair from; a new one is generated if it is missing
+    #[interactive_clap(long)]
+    pub master_seed_phrase: Option<String>,
+    ///The HD path of the key pair (m/44'/397'/0' by default)
+    #[interactive_clap(long)]
+    pub seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
+    ///The optional BIP39 passphrase; it is not stored, keep it to recover the key pair
+    #[interactive_clap(long)]
+    pub passphrase: Option<String>,
     #[interactive_clap(named_arg)]
     ///Execute a change method with online mode
     pub network: self::online_mode::NetworkArgs,
 }
 
 impl OperationMode {
+    pub fn from_cli(
+        optional_clap_variant: Option<<OperationMode as interactive_clap::ToCli>::CliVariant>,
+        context: (),
+    ) -> color_eyre::eyre::Result<Self> {
+        let master_seed_phrase = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.master_seed_phrase);
+        let seed_phrase_hd_path = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.seed_phrase_hd_path);
+        let passphrase = optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.passphrase);
+        let network = self::online_mode::NetworkArgs::from_cli(
+            optional_clap_variant.and_then(|clap_variant| match clap_variant.network {
+                Some(ClapNamedArgNetworkArgsForOperationMode::Network(cli_args)) => Some(cli_args),
+                None => None,
+            }),
+            context,
+        )?;
+        Ok(Self {
+            master_seed_phrase,
+            seed_phrase_hd_path,
+            passphrase,
+            network,
+        })
+    }
+
     pub async fn process(self) -> crate::CliResult {
-        self.network.process().await
+        let key_derivation_params = crate::common::KeyDerivationParams::from_seed_phrase_options(
+            self.master_seed_phrase,
+            self.seed_phrase_hd_path,
+            self.passphrase,
+        );
+        self.network.process(key_derivation_params).await
     }
 }
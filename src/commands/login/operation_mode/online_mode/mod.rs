@@ -8,7 +8,10 @@ pub struct NetworkArgs {
 }
 
 impl NetworkArgs {
-    pub async fn process(self) -> crate::CliResult {
-        self.selected_server.process().await
+    pub async fn process(
+        self,
+        key_derivation_params: crate::common::KeyDerivationParams,
+    ) -> crate::CliResult {
+        self.selected_server.process(key_derivation_params).await
     }
 }
@@ -58,22 +58,31 @@ impl From<SelectServerContext> for LoginCommandNetworkContext {
 }
 
 impl SelectServer {
-    pub async fn process(self) -> crate::CliResult {
+    pub async fn process(
+        self,
+        key_derivation_params: crate::common::KeyDerivationParams,
+    ) -> crate::CliResult {
         Ok(match self {
             SelectServer::Testnet(server) => {
                 let connection_config = crate::common::ConnectionConfig::Testnet;
-                server.process(connection_config).await?;
+                server
+                    .process(connection_config, key_derivation_params)
+                    .await?;
             }
             SelectServer::Mainnet(server) => {
                 let connection_config = crate::common::ConnectionConfig::Mainnet;
-                server.process(connection_config).await?;
+                server
+                    .process(connection_config, key_derivation_params)
+                    .await?;
             }
             SelectServer::Betanet(server) => {
                 let connection_config = crate::common::ConnectionConfig::Betanet;
-                server.process(connection_config).await?;
+                server
+                    .process(connection_config, key_derivation_params)
+                    .await?;
             }
             SelectServer::Custom(custom_server) => {
-                custom_server.process().await?;
+                custom_server.process(key_derivation_params).await?;
             }
         })
     }
@@ -4,7 +4,10 @@ use dialoguer::Input;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(context = super::SelectServerContext)]
-pub struct Server {}
+pub struct Server {
+    #[interactive_clap(subcommand)]
+    pub access_key_mode: crate::commands::login::access_key_mode::AccessKeyMode,
+}
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
 #[interactive_clap(input_context = super::SelectServerContext)]
@@ -12,6 +15,8 @@ pub struct Server {}
 pub struct CustomServer {
     #[interactive_clap(long)]
     pub url: crate::common::AvailableRpcServerUrl,
+    #[interactive_clap(subcommand)]
+    pub access_key_mode: crate::commands::login::access_key_mode::AccessKeyMode,
 }
 
 struct CustomServerContext {
@@ -19,7 +24,7 @@ struct CustomServerContext {
 }
 
 impl CustomServerContext {
-    fn _from_previous_context(
+    fn from_previous_context(
         _previous_context: super::SelectServerContext,
         scope: &<CustomServer as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> Self {
@@ -46,9 +51,17 @@ impl CustomServer {
             .interact_text()?)
     }
 
-    pub async fn process(self) -> crate::CliResult {
+    pub async fn process(
+        self,
+        key_derivation_params: crate::common::KeyDerivationParams,
+    ) -> crate::CliResult {
         let connection_config = crate::common::ConnectionConfig::from_custom_url(&self.url);
-        login(connection_config).await
+        login(
+            connection_config,
+            self.access_key_mode.function_call_permission(),
+            key_derivation_params,
+        )
+        .await
     }
 }
 
@@ -56,20 +69,56 @@ impl Server {
     pub async fn process(
         self,
         connection_config: crate::common::ConnectionConfig,
+        key_derivation_params: crate::common::KeyDerivationParams,
     ) -> crate::CliResult {
-        login(connection_config).await
+        login(
+            connection_config,
+            self.access_key_mode.function_call_permission(),
+            key_derivation_params,
+        )
+        .await
     }
 }
 
-async fn login(connection_config: crate::common::ConnectionConfig) -> crate::CliResult {
+async fn login(
+    connection_config: crate::common::ConnectionConfig,
+    function_call_permission: Option<near_primitives::account::FunctionCallPermission>,
+    key_derivation_params: crate::common::KeyDerivationParams,
+) -> crate::CliResult {
     let key_pair_properties: crate::common::KeyPairProperties =
-        crate::common::generate_keypair().await?;
-    let mut url: url::Url = connection_config.wallet_url().join("login/")?;
+        crate::common::generate_keypair(&key_derivation_params).await?;
+    let callback_server = match crate::commands::login::callback_server::CallbackServer::start() {
+        Ok(callback_server) => Some(callback_server),
+        Err(err) => {
+            println!(
+                "Failed to start the login callback server ({}), you will need to enter your account ID manually",
+                err
+            );
+            None
+        }
+    };
+    let mut url: url::Url = connection_config
+        .wallet_url()
+        .ok_or_else(|| {
+            color_eyre::Report::msg("There is no wallet to log in with on this network")
+        })?
+        .join("login/")?;
     url.query_pairs_mut()
         .append_pair("title", "NEAR CLI")
         .append_pair("public_key", &key_pair_properties.public_key_str);
-    // Use `success_url` once capture mode is implemented
-    //.append_pair("success_url", "http://127.0.0.1:8080");
+    if let Some(function_call_permission) = &function_call_permission {
+        url.query_pairs_mut()
+            .append_pair("contract_id", &function_call_permission.receiver_id);
+        for method_name in &function_call_permission.method_names {
+            url.query_pairs_mut()
+                .append_pair("methodNames", method_name);
+        }
+    }
+    if let Some(callback_server) = &callback_server {
+        url.query_pairs_mut()
+            .append_pair("success_url", &callback_server.success_url())
+            .append_pair("failure_url", &callback_server.failure_url());
+    }
     println!(
         "If your browser doesn't automatically open, please visit this URL:\n {}\n",
         &url.as_str()
@@ -80,12 +129,32 @@ async fn login(connection_config: crate::common::ConnectionConfig) -> crate::Cli
     let public_key: near_crypto::PublicKey =
         near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
 
-    let account_id = get_account_from_cli(public_key, connection_config.clone()).await?;
-    // save_account(&account_id, key_pair_properties, self.connection_config).await?
+    let account_id = match callback_server {
+        Some(callback_server) => {
+            println!("Waiting for the wallet to confirm the login...");
+            get_account_from_callback(
+                callback_server,
+                public_key.clone(),
+                connection_config.clone(),
+            )
+            .await?
+        }
+        None => get_account_from_cli(public_key.clone(), connection_config.clone()).await?,
+    };
+    let access_key_view =
+        verify_account_id(account_id.clone(), public_key, connection_config.clone()).await?;
+    if function_call_permission.is_some() {
+        if let near_primitives::views::AccessKeyPermissionView::FullAccess =
+            access_key_view.permission
+        {
+            println!("WARNING! The wallet granted a full access key instead of a function-call access key");
+        }
+    }
     crate::common::save_access_key_to_keychain(
         Some(connection_config),
         key_pair_properties.clone(),
         &account_id.to_string(),
+        access_key_view.permission,
     )
     .await
     .map_err(|err| {
@@ -94,6 +163,62 @@ async fn login(connection_config: crate::common::ConnectionConfig) -> crate::Cli
     Ok(())
 }
 
+async fn get_account_from_callback(
+    callback_server: crate::commands::login::callback_server::CallbackServer,
+    public_key: near_crypto::PublicKey,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    match callback_server.wait_for_callback().await {
+        Some(crate::commands::login::callback_server::WalletCallback::Success {
+            account_id,
+            public_key: callback_public_key,
+        }) => {
+            if callback_public_key != public_key.to_string() {
+                return Err(color_eyre::Report::msg(format!(
+                    "The wallet confirmed the public key {} instead of the requested {}",
+                    callback_public_key, public_key
+                )));
+            }
+            let account_id: near_primitives::types::AccountId =
+                account_id.parse().map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "The wallet returned an invalid account ID <{}>: {}",
+                        account_id, err
+                    ))
+                })?;
+            match verify_account_id(
+                account_id.clone(),
+                public_key.clone(),
+                network_connection_config.clone(),
+            )
+            .await
+            {
+                Ok(_) => {
+                    println!("Logged in as <{}>", account_id);
+                    Ok(account_id)
+                }
+                Err(err) => {
+                    println!(
+                        "The access key was not found on the account <{}> ({}), please enter your account ID manually",
+                        account_id, err
+                    );
+                    get_account_from_cli(public_key, network_connection_config).await
+                }
+            }
+        }
+        Some(crate::commands::login::callback_server::WalletCallback::Failure { error_code }) => {
+            Err(color_eyre::Report::msg(format!(
+                "The login was not completed in the wallet (error code: {})",
+                error_code
+            )))
+        }
+        None => {
+            println!("The wallet did not respond in time, please enter your account ID manually");
+            get_account_from_cli(public_key, network_connection_config).await
+        }
+    }
+}
+
 async fn get_account_from_cli(
     public_key: near_crypto::PublicKey,
     network_connection_config: crate::common::ConnectionConfig,
@@ -115,21 +240,28 @@ async fn verify_account_id(
     account_id: near_primitives::types::AccountId,
     public_key: near_crypto::PublicKey,
     network_connection_config: crate::common::ConnectionConfig,
-) -> crate::CliResult {
-    near_jsonrpc_client::JsonRpcClient::connect(&network_connection_config.rpc_url().as_str())
-        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
-            block_reference: near_primitives::types::Finality::Final.into(),
-            request: near_primitives::views::QueryRequest::ViewAccessKey {
-                account_id,
-                public_key,
-            },
-        })
-        .await
-        .map_err(|err| {
-            color_eyre::Report::msg(format!(
-                "Failed to fetch query for view access key: {:?}",
-                err
-            ))
-        })?;
-    Ok(())
+) -> color_eyre::eyre::Result<near_primitives::views::AccessKeyView> {
+    let query_view_access_key_response =
+        near_jsonrpc_client::JsonRpcClient::connect(&network_connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id,
+                    public_key,
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view access key: {:?}",
+                    err
+                ))
+            })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view) =
+        query_view_access_key_response.kind
+    {
+        Ok(access_key_view)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
 }
@@ -0,0 +1,134 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::LogoutAccountContext)]
+#[interactive_clap(output_context = super::LogoutAccessKeyContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct AccessKey {
+    pub public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(subcommand)]
+    pub logout_mode: super::logout_mode::LogoutMode,
+}
+
+struct AccessKeyContext {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: crate::types::account_id::AccountId,
+    public_key: crate::types::public_key::PublicKey,
+}
+
+impl AccessKeyContext {
+    pub fn from_previous_context(
+        previous_context: super::LogoutAccountContext,
+        scope: &<AccessKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            account_id: previous_context.account_id.clone(),
+            public_key: scope.public_key.clone(),
+        }
+    }
+}
+
+impl From<AccessKeyContext> for super::LogoutAccessKeyContext {
+    fn from(item: AccessKeyContext) -> Self {
+        Self {
+            connection_config: item.connection_config,
+            account_id: item.account_id,
+            public_key: item.public_key,
+        }
+    }
+}
+
+impl AccessKey {
+    pub fn from_cli(
+        optional_clap_variant: Option<<AccessKey as interactive_clap::ToCli>::CliVariant>,
+        context: super::LogoutAccountContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let public_key = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.public_key)
+        {
+            Some(public_key) => public_key,
+            None => Self::input_public_key(&context)?,
+        };
+        let permission =
+            actix::System::new().block_on(super::logout_mode::get_access_key_permission(
+                &context.connection_config,
+                &context.account_id,
+                &public_key.clone().into(),
+            ))?;
+        let is_function_call_access_key =
+            super::logout_mode::is_function_call_access_key(&permission);
+        let access_key_context = super::LogoutAccessKeyContext {
+            connection_config: context.connection_config,
+            account_id: context.account_id,
+            public_key: public_key.clone(),
+        };
+        let logout_mode = match optional_clap_variant
+            .and_then(|clap_variant| clap_variant.logout_mode)
+        {
+            Some(cli_logout_mode) => {
+                super::logout_mode::LogoutMode::from_cli(Some(cli_logout_mode), access_key_context)?
+            }
+            // A function-call access key cannot sign its own deletion
+            None if is_function_call_access_key => {
+                println!(
+                    "The access key {} is a function-call access key, the key deletion will be signed with another access key",
+                    public_key
+                );
+                super::logout_mode::LogoutMode::SignWithOtherKey(
+                    super::logout_mode::OtherKeychainKey::from_cli(None, access_key_context)?,
+                )
+            }
+            None => super::logout_mode::LogoutMode::from_cli(None, access_key_context)?,
+        };
+        Ok(Self {
+            public_key,
+            logout_mode,
+        })
+    }
+
+    fn input_public_key(
+        context: &super::LogoutAccountContext,
+    ) -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
+        let public_keys =
+            super::keychain_public_keys(&context.connection_config, &context.account_id);
+        if public_keys.is_empty() {
+            println!(
+                "There are no access keys found in the keychain for <{}>",
+                context.account_id
+            );
+            return Ok(Input::new()
+                .with_prompt("Enter the public key of the access key to log out")
+                .interact_text()?);
+        }
+        let items: Vec<String> = public_keys
+            .iter()
+            .map(|public_key| public_key.to_string())
+            .collect();
+        let select_public_key = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Which access key do you want to log out?")
+            .items(&items)
+            .default(0)
+            .interact()?;
+        Ok(crate::types::public_key::PublicKey(
+            public_keys[select_public_key].clone(),
+        ))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        account_id: crate::types::account_id::AccountId,
+    ) -> crate::CliResult {
+        self.logout_mode
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                account_id,
+                self.public_key.into(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,83 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::LogoutCommandNetworkContext)]
+#[interactive_clap(output_context = super::LogoutAccountContext)]
+pub struct Account {
+    pub account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    ///Specify an access key
+    pub access_key: super::access_key::AccessKey,
+}
+
+struct AccountContext {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: crate::types::account_id::AccountId,
+}
+
+impl AccountContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::LogoutCommandNetworkContext,
+        scope: &<Account as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            account_id: scope.account_id.clone(),
+        }
+    }
+}
+
+impl From<AccountContext> for super::LogoutAccountContext {
+    fn from(item: AccountContext) -> Self {
+        Self {
+            connection_config: item.connection_config,
+            account_id: item.account_id,
+        }
+    }
+}
+
+impl Account {
+    fn input_account_id(
+        context: &super::operation_mode::online_mode::select_server::LogoutCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        let account_ids: Vec<String> =
+            match super::keychain_dir(&context.connection_config).read_dir() {
+                Ok(dir) => dir
+                    .flatten()
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .collect(),
+                Err(_) => vec![],
+            };
+        if account_ids.is_empty() {
+            return Ok(Input::new()
+                .with_prompt("Which account do you want to log out from?")
+                .interact_text()?);
+        }
+        let select_account_id = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Which account do you want to log out from?")
+            .items(&account_ids)
+            .default(0)
+            .interact()?;
+        Ok(account_ids[select_account_id].parse()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.account_id.clone().into(),
+            receiver_id: self.account_id.clone().into(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.access_key
+            .process(
+                unsigned_transaction,
+                network_connection_config,
+                self.account_id,
+            )
+            .await
+    }
+}
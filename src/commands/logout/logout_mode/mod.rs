@@ -0,0 +1,248 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::LogoutAccessKeyContext)]
+///How do you want to delete the access key?
+pub enum LogoutMode {
+    #[strum_discriminants(strum(message = "Sign the key deletion with the same access key"))]
+    ///Delete the access key on chain signing the transaction with the same key
+    SignWithSameKey,
+    #[strum_discriminants(strum(
+        message = "Sign the key deletion with another access key from the keychain"
+    ))]
+    ///Delete the access key on chain signing the transaction with another keychain key
+    SignWithOtherKey(OtherKeychainKey),
+    #[strum_discriminants(strum(message = "Dry run: only show what would be removed"))]
+    ///Show what would be removed without changing anything
+    DryRun,
+}
+
+impl LogoutMode {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        account_id: crate::types::account_id::AccountId,
+        public_key: near_crypto::PublicKey,
+    ) -> crate::CliResult {
+        let files_to_remove =
+            super::keychain_files_to_remove(&network_connection_config, &account_id, &public_key);
+        let permission =
+            get_access_key_permission(&network_connection_config, &account_id, &public_key).await?;
+        let is_key_on_chain = permission.is_some();
+        let signer_public_key = match self {
+            Self::DryRun => {
+                if is_key_on_chain {
+                    println!(
+                        "\nThe access key {} would be deleted from <{}> on chain",
+                        public_key, account_id
+                    );
+                } else {
+                    println!(
+                        "\nThe access key {} is not found on <{}>, nothing would be deleted on chain",
+                        public_key, account_id
+                    );
+                }
+                if files_to_remove.is_empty() {
+                    println!("There are no keychain files to remove");
+                } else {
+                    println!("The following keychain files would be removed:");
+                    for file in &files_to_remove {
+                        println!("  {}", file.display());
+                    }
+                }
+                return Ok(());
+            }
+            Self::SignWithSameKey if is_function_call_access_key(&permission) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "The access key {} is a function-call access key and cannot sign its own deletion, sign it with another access key",
+                    public_key
+                )));
+            }
+            Self::SignWithSameKey => public_key.clone(),
+            Self::SignWithOtherKey(other_keychain_key) => {
+                let signer_public_key: near_crypto::PublicKey =
+                    other_keychain_key.signer_public_key.into();
+                if is_key_on_chain {
+                    match get_access_key_permission(
+                        &network_connection_config,
+                        &account_id,
+                        &signer_public_key,
+                    )
+                    .await?
+                    {
+                        Some(near_primitives::views::AccessKeyPermissionView::FullAccess) => {}
+                        Some(near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                            ..
+                        }) => {
+                            return Err(color_eyre::Report::msg(format!(
+                                "The access key {} is a function-call access key and cannot sign the key deletion, choose a full access key",
+                                signer_public_key
+                            )))
+                        }
+                        None => {
+                            return Err(color_eyre::Report::msg(format!(
+                                "The access key {} is not found on <{}> and cannot sign the key deletion",
+                                signer_public_key, account_id
+                            )))
+                        }
+                    }
+                }
+                signer_public_key
+            }
+        };
+        if is_key_on_chain {
+            let signer_access_key = crate::common::find_keychain_access_key(
+                &network_connection_config,
+                &account_id.to_string(),
+                &signer_public_key,
+            )?;
+            let action = near_primitives::transaction::Action::DeleteKey(
+                near_primitives::transaction::DeleteKeyAction {
+                    public_key: public_key.clone(),
+                },
+            );
+            let mut actions = prepopulated_unsigned_transaction.actions.clone();
+            actions.push(action);
+            let unsigned_transaction = near_primitives::transaction::Transaction {
+                actions,
+                ..prepopulated_unsigned_transaction
+            };
+            let sign_with_private_key = crate::commands::construct_transaction_command::sign_transaction::sign_with_private_key::SignPrivateKey {
+                signer_public_key: crate::types::public_key::PublicKey(signer_access_key.public_key),
+                signer_private_key: crate::types::secret_key::SecretKey(signer_access_key.private_key),
+                nonce: None,
+                block_hash: None,
+                submit: Some(crate::commands::construct_transaction_command::sign_transaction::Submit::Send),
+            };
+            let transaction_info = match sign_with_private_key
+                .process(
+                    unsigned_transaction,
+                    Some(network_connection_config.clone()),
+                )
+                .await?
+            {
+                Some(transaction_info) => transaction_info,
+                None => return Ok(()),
+            };
+            crate::common::print_transaction_status(
+                transaction_info.clone(),
+                Some(network_connection_config.clone()),
+            );
+            match transaction_info.status {
+                near_primitives::views::FinalExecutionStatus::SuccessValue(_) => {}
+                _ => {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The access key was not deleted on chain, so the keychain files were kept"
+                    )))
+                }
+            };
+        } else {
+            println!(
+                "The access key {} is not found on <{}>, only the keychain files will be removed",
+                public_key, account_id
+            );
+        }
+        for file in files_to_remove {
+            std::fs::remove_file(&file).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to remove the file {}: {}",
+                    file.display(),
+                    err
+                ))
+            })?;
+            println!("The file {} was removed", file.display());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::LogoutAccessKeyContext)]
+pub struct OtherKeychainKey {
+    pub signer_public_key: crate::types::public_key::PublicKey,
+}
+
+impl OtherKeychainKey {
+    fn input_signer_public_key(
+        context: &super::LogoutAccessKeyContext,
+    ) -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
+        let public_key: near_crypto::PublicKey = context.public_key.clone().into();
+        // Only full access keys can sign the deletion of an access key
+        let public_keys: Vec<near_crypto::PublicKey> = crate::common::keychain_access_keys(
+            &context.connection_config,
+            &context.account_id.to_string(),
+        )
+        .into_iter()
+        .filter(|access_key| access_key.is_full_access())
+        .map(|access_key| access_key.public_key)
+        .filter(|keychain_public_key| keychain_public_key != &public_key)
+        .collect();
+        if public_keys.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "There are no other full access keys found in the keychain for <{}>",
+                context.account_id
+            )));
+        }
+        let items: Vec<String> = public_keys
+            .iter()
+            .map(|public_key| public_key.to_string())
+            .collect();
+        let select_public_key = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Which access key should sign the key deletion?")
+            .items(&items)
+            .default(0)
+            .interact()?;
+        Ok(crate::types::public_key::PublicKey(
+            public_keys[select_public_key].clone(),
+        ))
+    }
+}
+
+/// The permission of the access key on chain, `None` if the key is not found on the account
+pub(super) async fn get_access_key_permission(
+    network_connection_config: &crate::common::ConnectionConfig,
+    account_id: &crate::types::account_id::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<Option<near_primitives::views::AccessKeyPermissionView>> {
+    match near_jsonrpc_client::JsonRpcClient::connect(network_connection_config.rpc_url().as_str())
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id: account_id.clone().into(),
+                public_key: public_key.clone(),
+            },
+        })
+        .await
+    {
+        Ok(near_jsonrpc_primitives::types::query::RpcQueryResponse {
+            kind:
+                near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view),
+            ..
+        }) => Ok(Some(access_key_view.permission)),
+        Ok(_) => Err(color_eyre::Report::msg(format!(
+            "Error call result for view access key"
+        ))),
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey { .. },
+            ),
+        )) => Ok(None),
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to fetch query for view access key: {:?}",
+            err
+        ))),
+    }
+}
+
+/// A function-call access key cannot sign the deletion of an access key, itself included
+pub(super) fn is_function_call_access_key(
+    permission: &Option<near_primitives::views::AccessKeyPermissionView>,
+) -> bool {
+    matches!(
+        permission,
+        Some(near_primitives::views::AccessKeyPermissionView::FunctionCall { .. })
+    )
+}
@@ -0,0 +1,61 @@
+mod access_key;
+mod account;
+mod logout_mode;
+pub mod operation_mode;
+
+#[derive(Clone)]
+pub struct LogoutAccountContext {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub account_id: crate::types::account_id::AccountId,
+}
+
+#[derive(Clone)]
+pub struct LogoutAccessKeyContext {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub account_id: crate::types::account_id::AccountId,
+    pub public_key: crate::types::public_key::PublicKey,
+}
+
+fn keychain_dir(connection_config: &crate::common::ConnectionConfig) -> std::path::PathBuf {
+    let mut path =
+        std::path::PathBuf::from(dirs::home_dir().expect("Impossible to get your home dir!"));
+    path.push(connection_config.dir_name());
+    path
+}
+
+/// Public keys of the account stored in the keychain
+fn keychain_public_keys(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &crate::types::account_id::AccountId,
+) -> Vec<near_crypto::PublicKey> {
+    crate::common::keychain_access_keys(connection_config, &account_id.to_string())
+        .into_iter()
+        .map(|access_key| access_key.public_key)
+        .collect()
+}
+
+/// The keychain files to remove on logout: the access key file and,
+/// if it holds the same key, the `<account_id>.json` file
+fn keychain_files_to_remove(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &crate::types::account_id::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> Vec<std::path::PathBuf> {
+    let mut files = vec![];
+    let access_key_path = crate::common::keychain_access_key_path(
+        connection_config,
+        &account_id.to_string(),
+        public_key,
+    );
+    if access_key_path.exists() {
+        files.push(access_key_path);
+    }
+    let account_path =
+        crate::common::keychain_account_path(connection_config, &account_id.to_string());
+    if let Ok(access_key) = crate::common::read_keychain_access_key(&account_path) {
+        if &access_key.public_key == public_key {
+            files.push(account_path);
+        }
+    }
+    files
+}
@@ -1,11 +1,18 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 pub mod add_command;
+pub mod bundle_command;
 pub mod construct_transaction_command;
+pub mod contract_command;
 pub mod delete_command;
+pub mod envelope_command;
 pub mod execute_command;
 pub mod generate_shell_completions_command;
 pub mod login;
+pub mod logout;
+pub mod rotate_key_command;
+pub mod sandbox_command;
+pub mod storage_management_command;
 pub mod transfer_command;
 pub mod utils_command;
 pub mod view_command;
@@ -18,6 +25,11 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "Login with wallet authorization"))]
     ///Use these to login with wallet authorization
     Login(self::login::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Logout: delete an access key and remove it from the keychain"
+    ))]
+    ///Use these to delete an access key on chain and remove it from the keychain
+    Logout(self::logout::operation_mode::OperationMode),
     #[strum_discriminants(strum(
         message = "View account, contract code, contract state, transaction, nonce, recent block hash"
     ))]
@@ -26,6 +38,9 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "Transfer tokens"))]
     ///Use these to transfer tokens
     Transfer(self::transfer_command::Currency),
+    #[strum_discriminants(strum(message = "Inspect, verify or upgrade a contract"))]
+    ///Use these to inspect, verify or upgrade a contract
+    Contract(self::contract_command::ContractActions),
     #[strum_discriminants(strum(message = "Execute function (contract method)"))]
     ///Execute function (contract method)
     Execute(self::execute_command::OptionMethod),
@@ -37,9 +52,28 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "Delete access key, account"))]
     ///Use these to delete access key, sub-account
     Delete(self::delete_command::DeleteAction),
+    #[strum_discriminants(strum(message = "Replace an access key with a new one"))]
+    ///Add a new access key with the permission of the old one and delete the old key in a single transaction
+    RotateKey(self::rotate_key_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Manage storage deposits on a contract (NEP-145)"))]
+    ///Use these to view, deposit, withdraw or unregister storage on a contract (NEP-145)
+    StorageManagement(self::storage_management_command::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Construct a new transaction"))]
     ///Prepare and, optionally, submit a new transaction
     ConstructTransaction(self::construct_transaction_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Prepare, sign offline and submit a transaction bundle"
+    ))]
+    ///Use these to sign a transaction on an air-gapped machine
+    Bundle(self::bundle_command::BundleCommand),
+    #[strum_discriminants(strum(
+        message = "Create, inspect, sign and finalize a transaction envelope"
+    ))]
+    ///Use these to collect a signature for a transaction described in a JSON envelope
+    Envelope(self::envelope_command::EnvelopeCommand),
+    #[strum_discriminants(strum(message = "Start, stop or check a local sandbox node"))]
+    ///Use these to manage a local sandbox node for integration testing
+    Sandbox(self::sandbox_command::SandboxCommand),
     #[strum_discriminants(strum(message = "Helpers"))]
     ///Helpers
     Utils(self::utils_command::Utils),
@@ -57,10 +91,19 @@ impl TopLevelCommand {
         };
         match self {
             Self::Add(add_action) => add_action.process(unsigned_transaction).await,
+            Self::Bundle(bundle_command) => bundle_command.process().await,
             Self::ConstructTransaction(mode) => mode.process(unsigned_transaction).await,
+            Self::Contract(contract_actions) => {
+                contract_actions.process(unsigned_transaction).await
+            }
             Self::Delete(delete_action) => delete_action.process(unsigned_transaction).await,
+            Self::Envelope(envelope_command) => envelope_command.process().await,
             Self::Execute(option_method) => option_method.process(unsigned_transaction).await,
             Self::Login(mode) => mode.process().await,
+            Self::Logout(mode) => mode.process(unsigned_transaction).await,
+            Self::RotateKey(mode) => mode.process(unsigned_transaction).await,
+            Self::Sandbox(sandbox_command) => sandbox_command.process().await,
+            Self::StorageManagement(mode) => mode.process(unsigned_transaction).await,
             Self::Transfer(currency) => currency.process(unsigned_transaction).await,
             Self::Utils(util_type) => util_type.process().await,
             Self::View(view_query_request) => view_query_request.process().await,
@@ -0,0 +1,485 @@
+use dialoguer::Input;
+use std::io::Write;
+use std::str::FromStr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+pub mod operation_mode;
+mod sender;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct RotateKey {
+    pub public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(subcommand)]
+    pub new_key: NewKey,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = crate::common::SignerContext)]
+///Where should the new access key come from?
+pub enum NewKey {
+    #[strum_discriminants(strum(message = "Generate a new key pair and save it to the keychain"))]
+    ///Generate a new key pair; it is saved to the keychain once the transaction succeeds
+    GenerateKeypair(GenerateKeypair),
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(message = "Use a key of Ledger Nano S/X device"))]
+    ///Use the public key of a Ledger device as the new access key
+    Ledger(LedgerKey),
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct GenerateKeypair {
+    #[interactive_clap(long)]
+    ///The type of the key pair: ed25519 or secp256k1
+    pub key_type: crate::types::key_type::KeyType,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = crate::common::SignerContext)]
+pub struct LedgerKey {
+    #[interactive_clap(long)]
+    ///The HD path of the new key on the Ledger device
+    pub seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    #[interactive_clap(subcommand)]
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl RotateKey {
+    fn input_public_key(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::public_key::PublicKey> {
+        Ok(Input::new()
+            .with_prompt("Enter the public key of the access key to be replaced")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        let connection_config = network_connection_config.ok_or_else(|| {
+            color_eyre::Report::msg("Rotating an access key requires a network connection")
+        })?;
+        let old_public_key: near_crypto::PublicKey = self.public_key.into();
+        let permission = get_access_key_permission(
+            &connection_config,
+            &prepopulated_unsigned_transaction.signer_id,
+            &old_public_key,
+        )
+        .await?;
+        self.new_key
+            .process(
+                prepopulated_unsigned_transaction,
+                connection_config,
+                old_public_key,
+                permission,
+            )
+            .await
+    }
+}
+
+impl NewKey {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        connection_config: crate::common::ConnectionConfig,
+        old_public_key: near_crypto::PublicKey,
+        permission: near_primitives::views::AccessKeyPermissionView,
+    ) -> crate::CliResult {
+        let account_id = prepopulated_unsigned_transaction.signer_id.clone();
+        match self {
+            Self::GenerateKeypair(generate_keypair) => {
+                let key_pair_properties =
+                    crate::common::generate_keypair(&crate::common::KeyDerivationParams {
+                        key_type: generate_keypair.key_type.into(),
+                        ..Default::default()
+                    })
+                    .await?;
+                let new_public_key =
+                    near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
+                // The old key may be deleted on chain even if sending fails on our side, so the
+                // new key is kept on disk until the transaction is known to have failed
+                let staged_key = StagedKey::new(
+                    &connection_config,
+                    &account_id,
+                    key_pair_properties,
+                    &permission,
+                )?;
+                match rotate_key(
+                    prepopulated_unsigned_transaction,
+                    &connection_config,
+                    old_public_key.clone(),
+                    new_public_key,
+                    permission.clone(),
+                    generate_keypair.sign_option,
+                )
+                .await
+                {
+                    Ok(Some(true)) => update_keychain(
+                        &connection_config,
+                        &account_id,
+                        &old_public_key,
+                        Some(&staged_key),
+                    )
+                    .map_err(|err| {
+                        staged_key.print_key_pair();
+                        err
+                    }),
+                    Ok(Some(false)) => staged_key.discard(),
+                    // The transaction was not sent, so the keychain is left untouched,
+                    // but the new key must not be lost in case it gets sent later.
+                    Ok(None) => {
+                        staged_key.print_key_pair();
+                        Ok(())
+                    }
+                    Err(err) => {
+                        staged_key.print_key_pair();
+                        Err(err)
+                    }
+                }
+            }
+            #[cfg(feature = "ledger")]
+            Self::Ledger(ledger_key) => {
+                let new_public_key = get_ledger_public_key(&ledger_key.seed_phrase_hd_path).await?;
+                match rotate_key(
+                    prepopulated_unsigned_transaction,
+                    &connection_config,
+                    old_public_key.clone(),
+                    new_public_key,
+                    permission.clone(),
+                    ledger_key.sign_option,
+                )
+                .await?
+                {
+                    // The private key stays on the device, so only the old key is dropped
+                    Some(true) => {
+                        update_keychain(&connection_config, &account_id, &old_public_key, None)
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl GenerateKeypair {
+    fn input_key_type(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::key_type::KeyType> {
+        crate::types::key_type::input_key_type()
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl LedgerKey {
+    fn input_seed_phrase_hd_path(
+        _context: &crate::common::SignerContext,
+    ) -> color_eyre::eyre::Result<crate::types::slip10::BIP32Path> {
+        Ok(Input::new()
+            .with_prompt("Enter seed phrase HD Path of the new key (if you not sure leave blank for default)")
+            .with_initial_text("44'/397'/0'/0'/1'")
+            .interact_text()?)
+    }
+}
+
+#[cfg(feature = "ledger")]
+async fn get_ledger_public_key(
+    seed_phrase_hd_path: &crate::types::slip10::BIP32Path,
+) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    println!(
+        "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+        seed_phrase_hd_path
+    );
+    let public_key = near_ledger::get_public_key(seed_phrase_hd_path.clone().into())
+        .await
+        .map_err(|near_ledger_error| {
+            color_eyre::Report::msg(format!(
+                "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                near_ledger_error
+            ))
+        })?;
+    Ok(near_crypto::PublicKey::ED25519(
+        near_crypto::ED25519PublicKey::from(public_key.to_bytes()),
+    ))
+}
+
+async fn get_access_key_permission(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<near_primitives::views::AccessKeyPermissionView> {
+    let access_key_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: account_id.clone(),
+                    public_key: public_key.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The key <{}> is not an access key of <{}>: {:?}",
+                    public_key, account_id, err
+                ))
+            })?;
+    match access_key_response.kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view) => {
+            Ok(access_key_view.permission)
+        }
+        _ => Err(color_eyre::Report::msg(
+            "Error call result for view access key",
+        )),
+    }
+}
+
+/// Adds the new key with the permission of the old one and deletes the old key in a single
+/// transaction. Returns `None` if the transaction was not sent, otherwise whether it succeeded.
+async fn rotate_key(
+    prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    connection_config: &crate::common::ConnectionConfig,
+    old_public_key: near_crypto::PublicKey,
+    new_public_key: near_crypto::PublicKey,
+    permission: near_primitives::views::AccessKeyPermissionView,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+) -> color_eyre::eyre::Result<Option<bool>> {
+    let mut actions = prepopulated_unsigned_transaction.actions.clone();
+    actions.push(near_primitives::transaction::Action::AddKey(
+        near_primitives::transaction::AddKeyAction {
+            public_key: new_public_key,
+            access_key: near_primitives::account::AccessKey {
+                nonce: 0,
+                permission: permission.into(),
+            },
+        },
+    ));
+    actions.push(near_primitives::transaction::Action::DeleteKey(
+        near_primitives::transaction::DeleteKeyAction {
+            public_key: old_public_key,
+        },
+    ));
+    let unsigned_transaction = near_primitives::transaction::Transaction {
+        actions,
+        ..prepopulated_unsigned_transaction
+    };
+    match sign_option
+        .process(unsigned_transaction, Some(connection_config.clone()))
+        .await?
+    {
+        Some(transaction_info) => {
+            let is_successful = matches!(
+                transaction_info.status,
+                near_primitives::views::FinalExecutionStatus::SuccessValue(_)
+            );
+            crate::common::print_transaction_status(
+                transaction_info,
+                Some(connection_config.clone()),
+            );
+            Ok(Some(is_successful))
+        }
+        None => Ok(None),
+    }
+}
+
+/// The new key pair, written to `<public_key>.json.pending` in the keychain before the
+/// transaction is sent and promoted to `<public_key>.json` once the transaction succeeds
+struct StagedKey {
+    key_pair_properties: crate::common::KeyPairProperties,
+    is_full_access: bool,
+    /// The content of the key file
+    buf: String,
+    pending_path: std::path::PathBuf,
+    key_path: std::path::PathBuf,
+}
+
+impl StagedKey {
+    fn new(
+        connection_config: &crate::common::ConnectionConfig,
+        account_id: &near_primitives::types::AccountId,
+        key_pair_properties: crate::common::KeyPairProperties,
+        permission: &near_primitives::views::AccessKeyPermissionView,
+    ) -> color_eyre::eyre::Result<Self> {
+        let is_full_access = matches!(
+            permission,
+            near_primitives::views::AccessKeyPermissionView::FullAccess
+        );
+        let mut key_data = serde_json::json!({
+            "master_seed_phrase": key_pair_properties.master_seed_phrase,
+            "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
+            "account_id": account_id,
+            "public_key": key_pair_properties.public_key_str,
+            "private_key": key_pair_properties.secret_keypair_str,
+        });
+        if !is_full_access {
+            key_data["permission"] = serde_json::json!(permission);
+        }
+        let buf = key_data.to_string();
+        let key_path = crate::common::keychain_access_key_path(
+            connection_config,
+            &account_id.to_string(),
+            &near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?,
+        );
+        if let Some(account_dir_path) = key_path.parent() {
+            std::fs::create_dir_all(account_dir_path)?;
+        }
+        let pending_path = key_path.with_extension("json.pending");
+        if let Err(err) = write_synced_file(&pending_path, &buf) {
+            let _ = std::fs::remove_file(&pending_path);
+            return Err(err);
+        }
+        println!(
+            "The new key pair is staged in a file {} until the transaction succeeds",
+            pending_path.display()
+        );
+        Ok(Self {
+            key_pair_properties,
+            is_full_access,
+            buf,
+            pending_path,
+            key_path,
+        })
+    }
+
+    fn print_key_pair(&self) {
+        println!(
+            "\nThe keychain is not updated. Keep the new key pair safe:\nMaster Seed Phrase: {}\nSeed Phrase HD Path: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
+            self.key_pair_properties.master_seed_phrase,
+            self.key_pair_properties.seed_phrase_hd_path.to_string(),
+            self.key_pair_properties.public_key_str,
+            self.key_pair_properties.secret_keypair_str,
+        );
+        if self.pending_path.exists() {
+            println!(
+                "The new key pair is also kept in a file {}",
+                self.pending_path.display()
+            );
+        }
+    }
+
+    /// Removes the staged key once the transaction has failed, as the key was never added
+    fn discard(self) -> crate::CliResult {
+        std::fs::remove_file(&self.pending_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to remove the file {}: {:?}",
+                self.pending_path.display(),
+                err
+            ))
+        })?;
+        println!(
+            "The transaction failed, the staged file {} is removed",
+            self.pending_path.display()
+        );
+        Ok(())
+    }
+}
+
+/// Replaces the old key with the new one in the keychain. All the new files are written and
+/// synced next to their destination first and renamed into place, so a failure while writing
+/// leaves the keychain as it was.
+fn update_keychain(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+    old_public_key: &near_crypto::PublicKey,
+    staged_key: Option<&StagedKey>,
+) -> crate::CliResult {
+    let old_key_path = crate::common::keychain_access_key_path(
+        connection_config,
+        &account_id.to_string(),
+        old_public_key,
+    );
+    let account_path =
+        crate::common::keychain_account_path(connection_config, &account_id.to_string());
+    let is_account_file_with_old_key = std::fs::read_to_string(&account_path)
+        .ok()
+        .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+        .and_then(|account_data| {
+            account_data
+                .get("public_key")
+                .and_then(|public_key| public_key.as_str())
+                .map(|public_key| public_key == old_public_key.to_string())
+        })
+        .unwrap_or(false);
+
+    // (written file, destination) pairs to be renamed once all of them are written
+    let mut pending_files: Vec<(std::path::PathBuf, std::path::PathBuf)> = vec![];
+    if let Some(staged_key) = staged_key {
+        pending_files.push((staged_key.pending_path.clone(), staged_key.key_path.clone()));
+        // The `<account_id>.json` file is reserved for full access keys
+        if is_account_file_with_old_key && staged_key.is_full_access {
+            pending_files.push((
+                write_temporary_file(&account_path, &staged_key.buf)?,
+                account_path.clone(),
+            ));
+        }
+    }
+
+    for (index, (written_path, path)) in pending_files.iter().enumerate() {
+        if let Err(err) = std::fs::rename(written_path, path) {
+            remove_temporary_files(&pending_files[index..]);
+            return Err(color_eyre::Report::msg(format!(
+                "Failed to move {} to {}: {:?}",
+                written_path.display(),
+                path.display(),
+                err
+            )));
+        }
+        println!(
+            "The data for the access key is saved in a file {}",
+            path.display()
+        );
+    }
+    let is_account_file_replaced = pending_files.iter().any(|(_, path)| path == &account_path);
+    if is_account_file_with_old_key && !is_account_file_replaced {
+        std::fs::remove_file(&account_path)?;
+        println!("The file {} is removed", account_path.display());
+    }
+    if old_key_path.exists() {
+        std::fs::remove_file(&old_key_path)?;
+        println!("The file {} is removed", old_key_path.display());
+    }
+    Ok(())
+}
+
+/// Writes the whole buffer and syncs it to the disk, so the file is complete before it is renamed
+fn write_synced_file(path: &std::path::Path, buf: &str) -> crate::CliResult {
+    let mut file = std::fs::File::create(path)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?;
+    file.write_all(buf.as_bytes())
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to write to file: {:?}", err)))?;
+    file.sync_all()
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to sync file: {:?}", err)))?;
+    Ok(())
+}
+
+fn write_temporary_file(
+    path: &std::path::Path,
+    buf: &str,
+) -> color_eyre::eyre::Result<std::path::PathBuf> {
+    let temporary_path = path.with_extension("json.tmp");
+    if let Err(err) = write_synced_file(&temporary_path, buf) {
+        let _ = std::fs::remove_file(&temporary_path);
+        return Err(err);
+    }
+    Ok(temporary_path)
+}
+
+/// Removes the `.json.tmp` files that are not renamed yet; the staged key is kept
+fn remove_temporary_files(pending_files: &[(std::path::PathBuf, std::path::PathBuf)]) {
+    for (written_path, _) in pending_files {
+        if written_path
+            .extension()
+            .map_or(false, |extension| extension == "tmp")
+        {
+            let _ = std::fs::remove_file(written_path);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+pub mod online_mode;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct OperationMode {
+    #[interactive_clap(named_arg)]
+    /// Rotate the access key with online mode
+    pub network: self::online_mode::NetworkArgs,
+}
+
+impl OperationMode {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.network
+            .process(prepopulated_unsigned_transaction)
+            .await
+    }
+}
+
+pub struct RotateKeyCommandNetworkContext {
+    pub connection_config: Option<crate::common::ConnectionConfig>,
+}
@@ -0,0 +1,234 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct CloneAccount {
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    source: SourceNetwork,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///Which network do you want to copy the account from?
+pub enum SourceNetwork {
+    #[strum_discriminants(strum(message = "Testnet"))]
+    ///Copy the account from testnet
+    Testnet(CloneOptions),
+    #[strum_discriminants(strum(message = "Mainnet"))]
+    ///Copy the account from mainnet
+    Mainnet(CloneOptions),
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct CloneOptions {
+    #[interactive_clap(subcommand)]
+    access_keys: AccessKeys,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///What access keys should the copy of the account have?
+pub enum AccessKeys {
+    #[strum_discriminants(strum(
+        message = "Replace the access keys with a new full access key saved in the sandbox keychain"
+    ))]
+    ///Replace the access keys with a new full access key saved in the sandbox keychain
+    ReplaceAccessKeys,
+    #[strum_discriminants(strum(message = "Keep the access keys of the original account"))]
+    ///Keep the access keys of the original account
+    KeepAccessKeys,
+}
+
+impl CloneAccount {
+    fn input_account_id(
+        _context: &(),
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What account do you want to copy into the sandbox?")
+            .interact_text()?)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let (connection_config, clone_options) = match self.source {
+            SourceNetwork::Testnet(clone_options) => {
+                (crate::common::ConnectionConfig::Testnet, clone_options)
+            }
+            SourceNetwork::Mainnet(clone_options) => {
+                (crate::common::ConnectionConfig::Mainnet, clone_options)
+            }
+        };
+        super::get_sandbox_status().await?;
+        let account_id: near_primitives::types::AccountId = self.account_id.into();
+        // All the queries are made at the same block, so the account, its code, its state and
+        // its access keys are consistent with each other
+        let block_hash =
+            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+                .call(near_jsonrpc_client::methods::block::RpcBlockRequest {
+                    block_reference: near_primitives::types::Finality::Final.into(),
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to fetch the final block: {:?}", err))
+                })?
+                .header
+                .hash;
+        let block_reference = near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Hash(block_hash),
+        );
+
+        let account_view =
+            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+                .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                    block_reference: block_reference.clone(),
+                    request: near_primitives::views::QueryRequest::ViewAccount {
+                        account_id: account_id.clone(),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch query for view account: {:?}",
+                        err
+                    ))
+                })?;
+        let account_view =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
+                account_view.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let mut records = vec![near_primitives::state_record::StateRecord::Account {
+            account_id: account_id.clone(),
+            account: near_primitives::account::Account::from(&account_view),
+        }];
+
+        let mut code_size = 0;
+        if account_view.code_hash != near_primitives::hash::CryptoHash::default() {
+            let code = crate::common::get_contract_code(
+                &connection_config,
+                account_id.clone(),
+                block_reference.clone(),
+            )
+            .await?;
+            code_size = code.len();
+            records.push(near_primitives::state_record::StateRecord::Contract {
+                account_id: account_id.clone(),
+                code,
+            });
+        }
+        let contract_state = crate::common::fetch_contract_state(
+            &connection_config,
+            account_id.clone(),
+            vec![],
+            block_reference.clone(),
+        )
+        .await?;
+        let state_entries = contract_state.len();
+        records.extend(contract_state.into_iter().map(|(data_key, value)| {
+            near_primitives::state_record::StateRecord::Data {
+                account_id: account_id.clone(),
+                data_key,
+                value,
+            }
+        }));
+
+        let (access_key_count, new_key_pair_properties) = match clone_options.access_keys {
+            AccessKeys::KeepAccessKeys => {
+                let access_key_list = near_jsonrpc_client::JsonRpcClient::connect(
+                    connection_config.rpc_url().as_str(),
+                )
+                .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                    block_reference,
+                    request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                        account_id: account_id.clone(),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch query for view key list: {:?}",
+                        err
+                    ))
+                })?;
+                let access_key_list =
+                    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(
+                        result,
+                    ) = access_key_list.kind
+                    {
+                        result
+                    } else {
+                        return Err(color_eyre::Report::msg(format!("Error call result")));
+                    };
+                let access_key_count = access_key_list.keys.len();
+                records.extend(access_key_list.keys.into_iter().map(|access_key| {
+                    near_primitives::state_record::StateRecord::AccessKey {
+                        account_id: account_id.clone(),
+                        public_key: access_key.public_key,
+                        access_key: access_key.access_key.into(),
+                    }
+                }));
+                (access_key_count, None)
+            }
+            AccessKeys::ReplaceAccessKeys => {
+                let key_pair_properties =
+                    crate::common::generate_keypair(&Default::default()).await?;
+                let public_key: near_crypto::PublicKey =
+                    key_pair_properties.public_key_str.parse()?;
+                records.push(near_primitives::state_record::StateRecord::AccessKey {
+                    account_id: account_id.clone(),
+                    public_key,
+                    access_key: near_primitives::account::AccessKey::full_access(),
+                });
+                (1, Some(key_pair_properties))
+            }
+        };
+
+        near_jsonrpc_client::JsonRpcClient::connect(
+            crate::common::ConnectionConfig::Sandbox.rpc_url().as_str(),
+        )
+        .call(
+            near_jsonrpc_client::methods::sandbox_patch_state::RpcSandboxPatchStateRequest {
+                records,
+            },
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to patch the sandbox state: {:?}", err))
+        })?;
+        if let Some(key_pair_properties) = new_key_pair_properties {
+            let secret_keypair_str = key_pair_properties.secret_keypair_str.clone();
+            crate::common::save_access_key_to_keychain(
+                Some(crate::common::ConnectionConfig::Sandbox),
+                key_pair_properties,
+                account_id.as_ref(),
+                near_primitives::views::AccessKeyPermissionView::FullAccess,
+            )
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to save the new access key of <{}> to the keychain ({}), its private key is {}",
+                    account_id, err, secret_keypair_str
+                ))
+            })?;
+        }
+        println!(
+            "\nThe account <{}> is copied into the sandbox: {}, {} state entries, {} access keys",
+            account_id,
+            if code_size == 0 {
+                "no contract code".to_string()
+            } else {
+                format!("{} bytes of contract code", code_size)
+            },
+            state_entries,
+            access_key_count
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod clone_account;
+mod start;
+
+/// The sandbox binary used when `--binary-path` is not given (the same variable near-workspaces reads)
+const SANDBOX_BIN_PATH_ENV: &str = "NEAR_SANDBOX_BIN_PATH";
+const SANDBOX_PID_FILE_NAME: &str = "near-sandbox.pid";
+const SANDBOX_LOG_FILE_NAME: &str = "near-sandbox.log";
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+pub struct SandboxCommand {
+    #[interactive_clap(subcommand)]
+    pub action: SandboxAction,
+}
+
+impl SandboxCommand {
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = ())]
+///What do you want to do with the local sandbox node?
+pub enum SandboxAction {
+    #[strum_discriminants(strum(message = "Start a local sandbox node"))]
+    ///Start a local sandbox node with a fresh temporary home directory
+    Start(self::start::Start),
+    #[strum_discriminants(strum(message = "Stop the local sandbox node"))]
+    ///Stop the local sandbox node and remove its home directory
+    Stop,
+    #[strum_discriminants(strum(message = "Show the status of the local sandbox node"))]
+    ///Show the status of the local sandbox node
+    Status,
+    #[strum_discriminants(strum(
+        message = "Copy an account with its contract code and state from testnet or mainnet into the sandbox"
+    ))]
+    ///Copy an account with its access keys, contract code and state from testnet or mainnet into the sandbox
+    CloneAccount(self::clone_account::CloneAccount),
+}
+
+impl SandboxAction {
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::Start(start) => start.process().await,
+            Self::Stop => stop(),
+            Self::Status => status().await,
+            Self::CloneAccount(clone_account) => clone_account.process().await,
+        }
+    }
+}
+
+/// The home directory of the sandbox node started by `near sandbox start`
+fn sandbox_home_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join("near-cli-sandbox")
+}
+
+fn read_sandbox_pid() -> Option<u32> {
+    std::fs::read_to_string(sandbox_home_dir().join(SANDBOX_PID_FILE_NAME))
+        .ok()
+        .and_then(|pid| pid.trim().parse().ok())
+}
+
+async fn get_sandbox_status() -> color_eyre::eyre::Result<near_primitives::views::StatusResponse> {
+    near_jsonrpc_client::JsonRpcClient::connect(
+        crate::common::ConnectionConfig::Sandbox.rpc_url().as_str(),
+    )
+    .call(near_jsonrpc_client::methods::status::RpcStatusRequest)
+    .await
+    .map_err(|err| {
+        color_eyre::Report::msg(format!("The sandbox node is not responding: {:?}", err))
+    })
+}
+
+fn stop() -> crate::CliResult {
+    let home_dir = sandbox_home_dir();
+    let pid = read_sandbox_pid().ok_or_else(|| {
+        color_eyre::Report::msg("No sandbox node started with `near sandbox start` was found")
+    })?;
+    let kill_status = if cfg!(windows) {
+        std::process::Command::new("taskkill")
+            .args(&["/PID", &pid.to_string(), "/F"])
+            .status()
+    } else {
+        std::process::Command::new("kill")
+            .arg(pid.to_string())
+            .status()
+    }
+    .map_err(|err| color_eyre::Report::msg(format!("Failed to stop the sandbox node: {}", err)))?;
+    if !kill_status.success() {
+        println!("The sandbox node (pid {}) was not running", pid);
+    } else {
+        println!("The sandbox node (pid {}) is stopped", pid);
+    }
+    std::fs::remove_dir_all(&home_dir).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Failed to remove the sandbox home directory {}: {}",
+            home_dir.display(),
+            err
+        ))
+    })?;
+    Ok(())
+}
+
+async fn status() -> crate::CliResult {
+    let home_dir = sandbox_home_dir();
+    match read_sandbox_pid() {
+        Some(pid) => println!(
+            "Sandbox node process: {}\nHome directory: {}\nLog file: {}",
+            pid,
+            home_dir.display(),
+            home_dir.join(SANDBOX_LOG_FILE_NAME).display()
+        ),
+        None => println!("No sandbox node was started with `near sandbox start`"),
+    }
+    match get_sandbox_status().await {
+        Ok(status) => println!(
+            "RPC: {} (chain {}, latest block #{} {})",
+            crate::common::ConnectionConfig::Sandbox.rpc_url(),
+            status.chain_id,
+            status.sync_info.latest_block_height,
+            status.sync_info.latest_block_hash
+        ),
+        Err(_) => println!(
+            "RPC: {} is not responding",
+            crate::common::ConnectionConfig::Sandbox.rpc_url()
+        ),
+    }
+    Ok(())
+}
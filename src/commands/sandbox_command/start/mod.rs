@@ -0,0 +1,158 @@
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = ())]
+#[interactive_clap(skip_default_from_cli)]
+pub struct Start {
+    ///The near-sandbox binary (default: $NEAR_SANDBOX_BIN_PATH or near-sandbox from PATH)
+    #[interactive_clap(long)]
+    binary_path: Option<crate::types::path_buf::PathBuf>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ValidatorKey {
+    account_id: near_primitives::types::AccountId,
+    #[serde(alias = "private_key")]
+    secret_key: near_crypto::SecretKey,
+}
+
+impl Start {
+    pub fn from_cli(
+        optional_clap_variant: Option<<Start as interactive_clap::ToCli>::CliVariant>,
+        _context: (),
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            binary_path: optional_clap_variant.and_then(|clap_variant| clap_variant.binary_path),
+        })
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        if super::get_sandbox_status().await.is_ok() {
+            return Err(color_eyre::Report::msg(format!(
+                "A sandbox node is already running at {}",
+                crate::common::ConnectionConfig::Sandbox.rpc_url()
+            )));
+        }
+        let binary_path: std::path::PathBuf = match self.binary_path {
+            Some(binary_path) => binary_path.into(),
+            None => std::env::var_os(super::SANDBOX_BIN_PATH_ENV)
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(|| "near-sandbox".into()),
+        };
+        let home_dir = super::sandbox_home_dir();
+        if home_dir.exists() {
+            std::fs::remove_dir_all(&home_dir)?;
+        }
+        let init_status = std::process::Command::new(&binary_path)
+            .arg("--home")
+            .arg(&home_dir)
+            .arg("init")
+            .status()
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to run {}: {}", binary_path.display(), err))
+            })?;
+        if !init_status.success() {
+            return Err(color_eyre::Report::msg(format!(
+                "Failed to initialize the sandbox home directory: {}",
+                init_status
+            )));
+        }
+
+        let log_path = home_dir.join(super::SANDBOX_LOG_FILE_NAME);
+        let log_file = std::fs::File::create(&log_path)?;
+        let mut child = std::process::Command::new(&binary_path)
+            .arg("--home")
+            .arg(&home_dir)
+            .arg("run")
+            .stdout(log_file.try_clone()?)
+            .stderr(log_file)
+            .spawn()
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to run {}: {}", binary_path.display(), err))
+            })?;
+        std::fs::write(
+            home_dir.join(super::SANDBOX_PID_FILE_NAME),
+            child.id().to_string(),
+        )?;
+        println!(
+            "Started the sandbox node (pid {}) in {}",
+            child.id(),
+            home_dir.display()
+        );
+
+        let mut attempts = 0;
+        while super::get_sandbox_status().await.is_err() {
+            attempts += 1;
+            if attempts > 120 {
+                // The home directory with the log is removed along with the node, so the end
+                // of the log goes into the error
+                let log = std::fs::read_to_string(&log_path).unwrap_or_default();
+                let log_lines: Vec<&str> = log.lines().collect();
+                let _ = child.kill();
+                let _ = child.wait();
+                std::fs::remove_dir_all(&home_dir)?;
+                return Err(color_eyre::Report::msg(format!(
+                    "The sandbox node did not start within a minute and was stopped. The end of its log:\n{}",
+                    log_lines[log_lines.len().saturating_sub(20)..].join("\n")
+                )));
+            }
+            actix::clock::sleep(std::time::Duration::from_millis(500)).await;
+        }
+        import_genesis_key(&home_dir).await?;
+        println!(
+            "The sandbox node is ready at {}, select the \"Sandbox\" network to use it",
+            crate::common::ConnectionConfig::Sandbox.rpc_url()
+        );
+        Ok(())
+    }
+}
+
+/// Saves the key of the genesis account into the sandbox keychain,
+/// removing the keys of the account saved for the previous sandbox node
+async fn import_genesis_key(home_dir: &std::path::Path) -> crate::CliResult {
+    let data = std::fs::read_to_string(home_dir.join("validator_key.json")).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to read the sandbox validator key: {}", err))
+    })?;
+    let validator_key: ValidatorKey = serde_json::from_str(&data).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Failed to parse the sandbox validator key: {}",
+            err
+        ))
+    })?;
+    let connection_config = crate::common::ConnectionConfig::Sandbox;
+    let account_id = validator_key.account_id.to_string();
+
+    // The keys of the previous sandbox node are not valid on the new one
+    let account_path = crate::common::keychain_account_path(&connection_config, &account_id);
+    let mut stale_files: Vec<std::path::PathBuf> = vec![];
+    if let Some(keychain_dir) = account_path.parent() {
+        if let Ok(dir) = keychain_dir.join(&account_id).read_dir() {
+            stale_files.extend(
+                dir.flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_file()),
+            );
+        }
+    }
+    if account_path.exists() {
+        stale_files.push(account_path);
+    }
+    for file in stale_files {
+        std::fs::remove_file(&file)?;
+        println!(
+            "The key file {} of the previous sandbox node is removed",
+            file.display()
+        );
+    }
+
+    crate::common::save_access_key_to_keychain(
+        Some(connection_config),
+        crate::common::KeyPairProperties::from_secret_key(&validator_key.secret_key)?,
+        &account_id,
+        near_primitives::views::AccessKeyPermissionView::FullAccess,
+    )
+    .await?;
+    println!(
+        "The key of the genesis account <{}> is saved in the sandbox keychain",
+        validator_key.account_id
+    );
+    Ok(())
+}
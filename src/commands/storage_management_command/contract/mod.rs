@@ -0,0 +1,92 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::operation_mode::online_mode::select_server::StorageManagementCommandNetworkContext)]
+#[interactive_clap(output_context = super::StorageManagementContext)]
+pub struct Contract {
+    #[interactive_clap(skip_default_from_cli)]
+    pub contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub storage_action: super::storage_action::StorageAction,
+}
+
+struct ContractContext {
+    connection_config: crate::common::ConnectionConfig,
+    contract_account_id: crate::types::account_id::AccountId,
+}
+
+impl ContractContext {
+    pub fn from_previous_context(
+        previous_context: super::operation_mode::online_mode::select_server::StorageManagementCommandNetworkContext,
+        scope: &<Contract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> Self {
+        Self {
+            connection_config: previous_context.connection_config.clone(),
+            contract_account_id: scope.contract_account_id.clone(),
+        }
+    }
+}
+
+impl From<ContractContext> for super::StorageManagementContext {
+    fn from(item: ContractContext) -> Self {
+        Self {
+            connection_config: item.connection_config,
+            contract_account_id: item.contract_account_id,
+        }
+    }
+}
+
+impl Contract {
+    fn from_cli_contract_account_id(
+        optional_cli_contract_account_id: Option<crate::types::account_id::AccountId>,
+        context: &super::operation_mode::online_mode::select_server::StorageManagementCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        match optional_cli_contract_account_id {
+            Some(cli_contract_account_id) => match crate::common::get_account_state(
+                &context.connection_config,
+                cli_contract_account_id.clone().into(),
+            )? {
+                Some(_) => Ok(cli_contract_account_id),
+                None => {
+                    println!("Account <{}> doesn't exist", cli_contract_account_id);
+                    Self::input_contract_account_id(&context)
+                }
+            },
+            None => Self::input_contract_account_id(&context),
+        }
+    }
+
+    fn input_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::StorageManagementCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt(
+                    "What is the account ID of the contract implementing storage management?",
+                )
+                .interact_text()?;
+            if let Some(_) = crate::common::get_account_state(
+                &context.connection_config,
+                account_id.clone().into(),
+            )? {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            };
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.contract_account_id.clone().into(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.storage_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,107 @@
+mod contract;
+pub mod operation_mode;
+mod signer;
+mod storage_action;
+
+/// Gas attached to the storage management function calls (30 TeraGas)
+const STORAGE_MANAGEMENT_GAS: u64 = 30000000000000;
+
+#[derive(Clone)]
+pub struct StorageManagementContext {
+    pub connection_config: crate::common::ConnectionConfig,
+    pub contract_account_id: crate::types::account_id::AccountId,
+}
+
+/// The result of the `storage_balance_bounds` method (NEP-145)
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StorageBalanceBounds {
+    #[serde(deserialize_with = "deserialize_near_balance")]
+    pub min: crate::common::NearBalance,
+    #[serde(default, deserialize_with = "deserialize_optional_near_balance")]
+    pub max: Option<crate::common::NearBalance>,
+}
+
+/// The result of the `storage_balance_of` method (NEP-145)
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StorageBalance {
+    #[serde(deserialize_with = "deserialize_near_balance")]
+    pub total: crate::common::NearBalance,
+    #[serde(deserialize_with = "deserialize_near_balance")]
+    pub available: crate::common::NearBalance,
+}
+
+fn deserialize_near_balance<'de, D>(deserializer: D) -> Result<crate::common::NearBalance, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let yoctonear_amount: String = serde::Deserialize::deserialize(deserializer)?;
+    yoctonear_amount
+        .parse::<u128>()
+        .map(crate::common::NearBalance::from_yoctonear)
+        .map_err(serde::de::Error::custom)
+}
+
+fn deserialize_optional_near_balance<'de, D>(
+    deserializer: D,
+) -> Result<Option<crate::common::NearBalance>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let yoctonear_amount: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    yoctonear_amount
+        .map(|yoctonear_amount| {
+            yoctonear_amount
+                .parse::<u128>()
+                .map(crate::common::NearBalance::from_yoctonear)
+                .map_err(serde::de::Error::custom)
+        })
+        .transpose()
+}
+
+pub async fn get_storage_balance_bounds(
+    connection_config: &crate::common::ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<StorageBalanceBounds> {
+    let call_result = crate::common::call_view_function(
+        connection_config,
+        contract_account_id,
+        "storage_balance_bounds",
+        b"{}".to_vec(),
+    )
+    .await?;
+    serde_json::from_slice(&call_result).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The contract returned unexpected storage balance bounds: {:?}",
+            err
+        ))
+    })
+}
+
+pub async fn get_storage_balance_of(
+    connection_config: &crate::common::ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<StorageBalance>> {
+    let args = serde_json::json!({ "account_id": account_id.to_string() });
+    let call_result = crate::common::call_view_function(
+        connection_config,
+        contract_account_id,
+        "storage_balance_of",
+        args.to_string().into_bytes(),
+    )
+    .await?;
+    serde_json::from_slice(&call_result).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The contract returned unexpected storage balance: {:?}",
+            err
+        ))
+    })
+}
+
+fn display_storage_balance_bounds(bounds: &StorageBalanceBounds) {
+    println!("Minimum storage balance: {}", bounds.min);
+    match &bounds.max {
+        Some(max) => println!("Maximum storage balance: {}", max),
+        None => println!("Maximum storage balance: unlimited"),
+    }
+}
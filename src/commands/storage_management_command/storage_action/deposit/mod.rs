@@ -0,0 +1,164 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct StorageDeposit {
+    pub account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    pub deposit_mode: DepositMode,
+}
+
+impl StorageDeposit {
+    fn input_account_id(
+        _context: &super::super::StorageManagementContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        Ok(Input::new()
+            .with_prompt("For which account do you want to make a storage deposit? (it may be your own account)")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.deposit_mode
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                self.account_id.into(),
+            )
+            .await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+///How much do you want to deposit?
+pub enum DepositMode {
+    #[strum_discriminants(strum(
+        message = "Registration only (deposit the minimum storage balance of the contract)"
+    ))]
+    ///Deposit the minimum storage balance of the contract to register the account
+    RegistrationOnly(RegistrationOnlyDeposit),
+    #[strum_discriminants(strum(message = "Deposit a custom amount"))]
+    ///Deposit a custom amount
+    Amount(DepositAmount),
+}
+
+impl DepositMode {
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        let contract_account_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let bounds = super::super::get_storage_balance_bounds(
+            &network_connection_config,
+            contract_account_id.clone(),
+        )
+        .await?;
+        let storage_balance = super::super::get_storage_balance_of(
+            &network_connection_config,
+            contract_account_id.clone(),
+            account_id.clone(),
+        )
+        .await?;
+        match self {
+            Self::RegistrationOnly(registration_only_deposit) => {
+                if storage_balance.is_some() {
+                    println!(
+                        "\nAccount <{}> is already registered on <{}>",
+                        account_id, contract_account_id
+                    );
+                    return Ok(());
+                }
+                let unsigned_transaction = super::add_function_call_action(
+                    prepopulated_unsigned_transaction,
+                    "storage_deposit",
+                    serde_json::json!({
+                        "account_id": account_id.to_string(),
+                        "registration_only": true,
+                    }),
+                    bounds.min.to_yoctonear(),
+                );
+                registration_only_deposit
+                    .signer
+                    .process(unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Self::Amount(deposit_amount) => {
+                match &storage_balance {
+                    None if deposit_amount.amount < bounds.min => {
+                        return Err(color_eyre::Report::msg(format!(
+                            "The deposit of {} is less than the minimum storage balance of {} required to register <{}>",
+                            deposit_amount.amount, bounds.min, account_id
+                        )));
+                    }
+                    _ => {}
+                };
+                if let Some(max) = &bounds.max {
+                    let total = storage_balance
+                        .as_ref()
+                        .map(|storage_balance| storage_balance.total.to_yoctonear())
+                        .unwrap_or_default()
+                        + deposit_amount.amount.to_yoctonear();
+                    if total > max.to_yoctonear() {
+                        println!(
+                            "The maximum storage balance is {}, the excess of the deposit will be refunded",
+                            max
+                        );
+                    }
+                }
+                let unsigned_transaction = super::add_function_call_action(
+                    prepopulated_unsigned_transaction,
+                    "storage_deposit",
+                    serde_json::json!({ "account_id": account_id.to_string() }),
+                    deposit_amount.amount.to_yoctonear(),
+                );
+                deposit_amount
+                    .signer
+                    .process(unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct RegistrationOnlyDeposit {
+    #[interactive_clap(named_arg)]
+    ///Specify a signer
+    pub signer: super::super::signer::Sender,
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct DepositAmount {
+    pub amount: crate::common::NearBalance,
+    #[interactive_clap(named_arg)]
+    ///Specify a signer
+    pub signer: super::super::signer::Sender,
+}
+
+impl DepositAmount {
+    fn input_amount(
+        context: &super::super::StorageManagementContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        let bounds = actix::System::new().block_on(super::super::get_storage_balance_bounds(
+            &context.connection_config,
+            context.contract_account_id.clone().into(),
+        ))?;
+        super::super::display_storage_balance_bounds(&bounds);
+        Ok(Input::new()
+            .with_prompt(
+                "How much do you want to deposit? (example: 10NEAR or 0.5near or 10000yoctonear)",
+            )
+            .with_initial_text(bounds.min.to_string())
+            .interact_text()?)
+    }
+}
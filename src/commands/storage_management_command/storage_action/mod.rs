@@ -0,0 +1,96 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod deposit;
+mod unregister;
+mod view_balance;
+mod withdraw;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::StorageManagementContext)]
+///What do you want to do with the storage?
+pub enum StorageAction {
+    #[strum_discriminants(strum(message = "View storage balance bounds of the contract"))]
+    ///View the minimum and maximum storage balance accepted by the contract
+    ViewBounds,
+    #[strum_discriminants(strum(message = "View storage balance of an account"))]
+    ///View the storage balance of an account
+    ViewBalance(self::view_balance::ViewStorageBalance),
+    #[strum_discriminants(strum(message = "Make a storage deposit for an account"))]
+    ///Make a storage deposit for an account
+    Deposit(self::deposit::StorageDeposit),
+    #[strum_discriminants(strum(message = "Withdraw the available storage balance"))]
+    ///Withdraw the available storage balance
+    Withdraw(self::withdraw::StorageWithdraw),
+    #[strum_discriminants(strum(
+        message = "Unregister the account and return its storage deposit"
+    ))]
+    ///Unregister the account and return its storage deposit
+    Unregister(self::unregister::StorageUnregister),
+}
+
+impl StorageAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            Self::ViewBounds => {
+                let bounds = super::get_storage_balance_bounds(
+                    &network_connection_config,
+                    prepopulated_unsigned_transaction.receiver_id,
+                )
+                .await?;
+                super::display_storage_balance_bounds(&bounds);
+                Ok(())
+            }
+            Self::ViewBalance(view_storage_balance) => {
+                view_storage_balance
+                    .process(
+                        prepopulated_unsigned_transaction.receiver_id,
+                        network_connection_config,
+                    )
+                    .await
+            }
+            Self::Deposit(storage_deposit) => {
+                storage_deposit
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Self::Withdraw(storage_withdraw) => {
+                storage_withdraw
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Self::Unregister(storage_unregister) => {
+                storage_unregister
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// Appends a storage management function call to the transaction
+fn add_function_call_action(
+    prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    method_name: &str,
+    args: serde_json::Value,
+    deposit: u128,
+) -> near_primitives::transaction::Transaction {
+    let action = near_primitives::transaction::Action::FunctionCall(
+        near_primitives::transaction::FunctionCallAction {
+            method_name: method_name.to_string(),
+            args: args.to_string().into_bytes(),
+            gas: super::STORAGE_MANAGEMENT_GAS,
+            deposit,
+        },
+    );
+    let mut actions = prepopulated_unsigned_transaction.actions.clone();
+    actions.push(action);
+    near_primitives::transaction::Transaction {
+        actions,
+        ..prepopulated_unsigned_transaction
+    }
+}
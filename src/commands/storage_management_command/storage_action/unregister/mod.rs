@@ -0,0 +1,26 @@
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct StorageUnregister {
+    #[interactive_clap(named_arg)]
+    ///Specify a signer
+    pub signer: super::super::signer::Sender,
+}
+
+impl StorageUnregister {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        // The storage management standard requires exactly 1 yoctoNEAR attached to `storage_unregister`
+        let unsigned_transaction = super::add_function_call_action(
+            prepopulated_unsigned_transaction,
+            "storage_unregister",
+            serde_json::json!({}),
+            1,
+        );
+        self.signer
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
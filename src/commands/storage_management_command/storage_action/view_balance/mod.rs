@@ -0,0 +1,45 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct ViewStorageBalance {
+    pub account_id: crate::types::account_id::AccountId,
+}
+
+impl ViewStorageBalance {
+    fn input_account_id(
+        _context: &super::super::StorageManagementContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID to view the storage balance for?")
+            .interact_text()?)
+    }
+
+    pub async fn process(
+        self,
+        contract_account_id: near_primitives::types::AccountId,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match super::super::get_storage_balance_of(
+            &network_connection_config,
+            contract_account_id.clone(),
+            self.account_id.clone().into(),
+        )
+        .await?
+        {
+            Some(storage_balance) => {
+                println!(
+                    "\nStorage balance of <{}> on <{}>:",
+                    self.account_id, contract_account_id
+                );
+                println!("Total:     {}", storage_balance.total);
+                println!("Available: {}", storage_balance.available);
+            }
+            None => println!(
+                "\nAccount <{}> is not registered on <{}>",
+                self.account_id, contract_account_id
+            ),
+        };
+        Ok(())
+    }
+}
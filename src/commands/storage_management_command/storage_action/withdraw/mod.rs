@@ -0,0 +1,101 @@
+use dialoguer::Input;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct StorageWithdraw {
+    #[interactive_clap(subcommand)]
+    pub withdraw_mode: WithdrawMode,
+}
+
+impl StorageWithdraw {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.withdraw_mode
+            .process(prepopulated_unsigned_transaction, network_connection_config)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+///How much do you want to withdraw?
+pub enum WithdrawMode {
+    #[strum_discriminants(strum(message = "The whole available storage balance"))]
+    ///Withdraw the whole available storage balance
+    All(WithdrawAll),
+    #[strum_discriminants(strum(message = "A custom amount"))]
+    ///Withdraw a custom amount
+    Amount(WithdrawAmount),
+}
+
+impl WithdrawMode {
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        // The storage management standard requires exactly 1 yoctoNEAR attached to `storage_withdraw`
+        match self {
+            Self::All(withdraw_all) => {
+                let unsigned_transaction = super::add_function_call_action(
+                    prepopulated_unsigned_transaction,
+                    "storage_withdraw",
+                    serde_json::json!({}),
+                    1,
+                );
+                withdraw_all
+                    .signer
+                    .process(unsigned_transaction, network_connection_config)
+                    .await
+            }
+            Self::Amount(withdraw_amount) => {
+                let unsigned_transaction = super::add_function_call_action(
+                    prepopulated_unsigned_transaction,
+                    "storage_withdraw",
+                    serde_json::json!({
+                        "amount": withdraw_amount.amount.to_yoctonear().to_string(),
+                    }),
+                    1,
+                );
+                withdraw_amount
+                    .signer
+                    .process(unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct WithdrawAll {
+    #[interactive_clap(named_arg)]
+    ///Specify a signer
+    pub signer: super::super::signer::Sender,
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::StorageManagementContext)]
+pub struct WithdrawAmount {
+    pub amount: crate::common::NearBalance,
+    #[interactive_clap(named_arg)]
+    ///Specify a signer
+    pub signer: super::super::signer::Sender,
+}
+
+impl WithdrawAmount {
+    fn input_amount(
+        _context: &super::super::StorageManagementContext,
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
+            .with_prompt(
+                "How much do you want to withdraw? (example: 10NEAR or 0.5near or 10000yoctonear)",
+            )
+            .interact_text()?)
+    }
+}
@@ -93,6 +93,7 @@ impl CombineTransactionSignature {
             self.signature,
             self.unsigned_transaction,
         );
+        crate::common::verify_transaction_signature(&signed_transaction)?;
         let serialize_to_base64 = near_primitives::serialize::to_base64(
             signed_transaction
                 .try_to_vec()
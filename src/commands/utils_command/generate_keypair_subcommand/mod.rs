@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 /// Generate a key pair of private and public keys (use it anywhere you need
-/// Ed25519 keys)
+/// Ed25519 or Secp256k1 keys)
 #[derive(Debug, Clone, clap::Clap)]
 pub struct CliGenerateKeypair {
     #[clap(long)]
@@ -10,8 +10,16 @@ pub struct CliGenerateKeypair {
     pub new_master_seed_phrase_words_count: usize,
     #[clap(long, default_value = "m/44'/397'/0'")]
     pub seed_phrase_hd_path: slip10::BIP32Path,
+    /// The optional BIP39 passphrase; it is not stored, keep it to recover the keys
+    #[clap(long)]
+    pub passphrase: Option<String>,
+    /// Derive the keys of several account indices, e.g. "0-4" (the last component of the HD path)
+    #[clap(long)]
+    pub account_indices: Option<AccountIndices>,
     #[clap(long, default_value = "plaintext")]
     pub format: crate::common::OutputFormat,
+    #[clap(long, default_value = "ed25519")]
+    pub key_type: crate::types::key_type::KeyType,
 }
 
 impl Default for CliGenerateKeypair {
@@ -20,37 +28,92 @@ impl Default for CliGenerateKeypair {
             master_seed_phrase: None,
             new_master_seed_phrase_words_count: 12,
             seed_phrase_hd_path: slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
+            passphrase: None,
+            account_indices: None,
             format: crate::common::OutputFormat::Json,
+            key_type: Default::default(),
+        }
+    }
+}
+
+/// An inclusive range of account indices: "3" or "0-4"
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountIndices(std::ops::RangeInclusive<u32>);
+
+impl FromStr for AccountIndices {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_index = |index: &str| {
+            index
+                .trim()
+                .parse::<u32>()
+                .map_err(|err| format!("Invalid account index <{}>: {}", index, err))
+        };
+        let (first, last) = match s.split_once('-') {
+            Some((first, last)) => (parse_index(first)?, parse_index(last)?),
+            None => {
+                let index = parse_index(s)?;
+                (index, index)
+            }
+        };
+        if first > last {
+            return Err(format!("The account index range <{}> is empty", s));
         }
+        Ok(Self(first..=last))
     }
 }
 
 impl CliGenerateKeypair {
     pub async fn process(self) -> crate::CliResult {
-        let key_pair_properties = crate::common::generate_keypair().await?;
+        let params = crate::common::KeyDerivationParams {
+            master_seed_phrase: self.master_seed_phrase.clone(),
+            new_master_seed_phrase_words_count: self.new_master_seed_phrase_words_count,
+            seed_phrase_hd_path: self.seed_phrase_hd_path.clone(),
+            passphrase: self.passphrase.clone().unwrap_or_default(),
+            key_type: self.key_type.into(),
+        };
+        let key_pairs = match &self.account_indices {
+            Some(account_indices) => {
+                crate::common::generate_keypairs(&params, account_indices.0.clone()).await?
+            }
+            None => vec![crate::common::generate_keypair(&params).await?],
+        };
         match self.format {
             crate::common::OutputFormat::Plaintext => {
-                println!(
-                    "Master Seed Phrase: {}\nSeed Phrase HD Path: {}\nImplicit Account ID: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
-                    key_pair_properties.master_seed_phrase,
-                    key_pair_properties.seed_phrase_hd_path.to_string(),
-                    key_pair_properties.implicit_account_id,
-                    key_pair_properties.public_key_str,
-                    key_pair_properties.secret_keypair_str,
-                );
+                for key_pair_properties in &key_pairs {
+                    println!(
+                        "Master Seed Phrase: {}\nSeed Phrase HD Path: {}",
+                        key_pair_properties.master_seed_phrase,
+                        key_pair_properties.seed_phrase_hd_path.to_string(),
+                    );
+                    if let Some(implicit_account_id) = &key_pair_properties.implicit_account_id {
+                        println!("Implicit Account ID: {}", implicit_account_id);
+                    }
+                    println!(
+                        "Public Key: {}\nSECRET KEYPAIR: {}\n",
+                        key_pair_properties.public_key_str, key_pair_properties.secret_keypair_str,
+                    );
+                }
             }
             crate::common::OutputFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&serde_json::json!({
-                        "master_seed_phrase": key_pair_properties.master_seed_phrase,
-                        "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
-                        "account_id": key_pair_properties.implicit_account_id,
-                        "public_key": key_pair_properties.public_key_str,
-                        "private_key": key_pair_properties.secret_keypair_str,
-                    }))
-                    .unwrap()
-                );
+                let key_pairs_json = key_pairs
+                    .iter()
+                    .map(|key_pair_properties| {
+                        serde_json::json!({
+                            "master_seed_phrase": key_pair_properties.master_seed_phrase,
+                            "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
+                            "account_id": key_pair_properties.implicit_account_id,
+                            "public_key": key_pair_properties.public_key_str,
+                            "private_key": key_pair_properties.secret_keypair_str,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let output = match (&self.account_indices, key_pairs_json.as_slice()) {
+                    (None, [key_pair_json]) => key_pair_json.clone(),
+                    _ => serde_json::Value::Array(key_pairs_json),
+                };
+                println!("{}", serde_json::to_string_pretty(&output).unwrap());
             }
         };
         Ok(())
@@ -5,10 +5,17 @@ mod combine_transaction_subcommand_with_signature;
 pub mod generate_keypair_subcommand;
 #[cfg(feature = "ledger")]
 mod ledger_publickey_subcommand;
+mod recover_seed_phrase;
+mod scan_qr_code;
 mod send_signed_transaction;
+mod show_qr_code;
+mod sign_message;
 mod sign_transaction_subcommand_with_secret_key;
 #[cfg(feature = "ledger")]
 mod sign_transaction_with_ledger_subcommand;
+mod split_seed_phrase;
+mod verify_message;
+mod verify_signature;
 mod view_serialized_transaction;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
@@ -48,6 +55,20 @@ pub enum CliUtil {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     /// Send signed transaction
     SendSignedTransaction(self::send_signed_transaction::operation_mode::CliOperationMode),
+    /// Show a serialized transaction as QR codes
+    ShowQrCode(self::show_qr_code::CliShowQrCode),
+    /// Reassemble a serialized transaction from scanned QR code parts
+    ScanQrCode(self::scan_qr_code::CliScanQrCode),
+    /// Verify the signature of a signed transaction or of an arbitrary message
+    VerifySignature(self::verify_signature::CliVerifySignature),
+    /// Sign a message to prove the ownership of an account (NEP-413), with a private key, the keychain or a Ledger device
+    SignMessage(self::sign_message::CliSignMessage),
+    /// Verify a signed message (NEP-413)
+    VerifyMessage(self::verify_message::CliVerifyMessage),
+    /// Split a master seed phrase into SLIP-39 shares
+    SplitSeedPhrase(self::split_seed_phrase::CliSplitSeedPhrase),
+    /// Recover a master seed phrase from SLIP-39 shares
+    RecoverSeedPhrase(self::recover_seed_phrase::CliRecoverSeedPhrase),
 }
 
 #[derive(Debug, Clone, EnumDiscriminants)]
@@ -75,6 +96,20 @@ pub enum Util {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     #[strum_discriminants(strum(message = "Send signed transaction"))]
     SendSignedTransaction(self::send_signed_transaction::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Show a transaction as QR codes"))]
+    ShowQrCode(self::show_qr_code::ShowQrCode),
+    #[strum_discriminants(strum(message = "Reassemble a transaction from scanned QR codes"))]
+    ScanQrCode(self::scan_qr_code::ScanQrCode),
+    #[strum_discriminants(strum(message = "Verify a signature"))]
+    VerifySignature(self::verify_signature::VerifySignature),
+    #[strum_discriminants(strum(message = "Sign a message (NEP-413)"))]
+    SignMessage(self::sign_message::SignMessage),
+    #[strum_discriminants(strum(message = "Verify a signed message (NEP-413)"))]
+    VerifyMessage(self::verify_message::VerifyMessage),
+    #[strum_discriminants(strum(message = "Split a seed phrase into SLIP-39 shares"))]
+    SplitSeedPhrase(self::split_seed_phrase::SplitSeedPhrase),
+    #[strum_discriminants(strum(message = "Recover a key from SLIP-39 shares"))]
+    RecoverSeedPhrase(self::recover_seed_phrase::RecoverSeedPhrase),
 }
 
 impl interactive_clap::ToCli for Util {
@@ -121,6 +156,41 @@ impl CliUtil {
                 args.push_front("send-signed-transaction".to_owned());
                 args
             }
+            Self::ShowQrCode(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("show-qr-code".to_owned());
+                args
+            }
+            Self::ScanQrCode(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("scan-qr-code".to_owned());
+                args
+            }
+            Self::VerifySignature(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("verify-signature".to_owned());
+                args
+            }
+            Self::SignMessage(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-message".to_owned());
+                args
+            }
+            Self::VerifyMessage(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("verify-message".to_owned());
+                args
+            }
+            Self::SplitSeedPhrase(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("split-seed-phrase".to_owned());
+                args
+            }
+            Self::RecoverSeedPhrase(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("recover-seed-phrase".to_owned());
+                args
+            }
         }
     }
 }
@@ -147,6 +217,19 @@ impl From<Util> for CliUtil {
             Util::SendSignedTransaction(operation_mode) => {
                 Self::SendSignedTransaction(operation_mode.into())
             }
+            Util::ShowQrCode(show_qr_code) => Self::ShowQrCode(show_qr_code.into()),
+            Util::ScanQrCode(scan_qr_code) => Self::ScanQrCode(scan_qr_code.into()),
+            Util::VerifySignature(verify_signature) => {
+                Self::VerifySignature(verify_signature.into())
+            }
+            Util::SignMessage(sign_message) => Self::SignMessage(sign_message.into()),
+            Util::VerifyMessage(verify_message) => Self::VerifyMessage(verify_message.into()),
+            Util::SplitSeedPhrase(split_seed_phrase) => {
+                Self::SplitSeedPhrase(split_seed_phrase.into())
+            }
+            Util::RecoverSeedPhrase(recover_seed_phrase) => {
+                Self::RecoverSeedPhrase(recover_seed_phrase.into())
+            }
         }
     }
 }
@@ -192,6 +275,27 @@ impl Util {
             Some(CliUtil::SendSignedTransaction(cli_operation_mode)) => {
                 Ok(Util::SendSignedTransaction(cli_operation_mode.into()))
             }
+            Some(CliUtil::ShowQrCode(cli_show_qr_code)) => {
+                Ok(Util::ShowQrCode(cli_show_qr_code.into()))
+            }
+            Some(CliUtil::ScanQrCode(cli_scan_qr_code)) => {
+                Ok(Util::ScanQrCode(cli_scan_qr_code.into()))
+            }
+            Some(CliUtil::VerifySignature(cli_verify_signature)) => {
+                Ok(Util::VerifySignature(cli_verify_signature.into()))
+            }
+            Some(CliUtil::SignMessage(cli_sign_message)) => Ok(Util::SignMessage(
+                self::sign_message::SignMessage::from_cli(cli_sign_message)?,
+            )),
+            Some(CliUtil::VerifyMessage(cli_verify_message)) => {
+                Ok(Util::VerifyMessage(cli_verify_message.into()))
+            }
+            Some(CliUtil::SplitSeedPhrase(cli_split_seed_phrase)) => {
+                Ok(Util::SplitSeedPhrase(cli_split_seed_phrase.into()))
+            }
+            Some(CliUtil::RecoverSeedPhrase(cli_recover_seed_phrase)) => {
+                Ok(Util::RecoverSeedPhrase(cli_recover_seed_phrase.into()))
+            }
             None => Self::choose_variant(context),
         }
     }
@@ -235,6 +339,13 @@ impl Util {
             UtilDiscriminants::SendSignedTransaction => {
                 CliUtil::SendSignedTransaction(Default::default())
             }
+            UtilDiscriminants::ShowQrCode => CliUtil::ShowQrCode(Default::default()),
+            UtilDiscriminants::ScanQrCode => CliUtil::ScanQrCode(Default::default()),
+            UtilDiscriminants::VerifySignature => CliUtil::VerifySignature(Default::default()),
+            UtilDiscriminants::SignMessage => CliUtil::SignMessage(Default::default()),
+            UtilDiscriminants::VerifyMessage => CliUtil::VerifyMessage(Default::default()),
+            UtilDiscriminants::SplitSeedPhrase => CliUtil::SplitSeedPhrase(Default::default()),
+            UtilDiscriminants::RecoverSeedPhrase => CliUtil::RecoverSeedPhrase(Default::default()),
         };
         Ok(Self::from_cli(Some(cli_util), context)?)
     }
@@ -254,6 +365,13 @@ impl Util {
             #[cfg(feature = "ledger")]
             Self::LedgerPublicKey(ledger_publickey) => ledger_publickey.process().await,
             Self::SendSignedTransaction(operation_mode) => operation_mode.process().await,
+            Self::ShowQrCode(show_qr_code) => show_qr_code.process().await,
+            Self::ScanQrCode(scan_qr_code) => scan_qr_code.process().await,
+            Self::VerifySignature(verify_signature) => verify_signature.process().await,
+            Self::SignMessage(sign_message) => sign_message.process().await,
+            Self::VerifyMessage(verify_message) => verify_message.process().await,
+            Self::SplitSeedPhrase(split_seed_phrase) => split_seed_phrase.process().await,
+            Self::RecoverSeedPhrase(recover_seed_phrase) => recover_seed_phrase.process().await,
         }
     }
 }
@@ -0,0 +1,156 @@
+use dialoguer::Input;
+use std::str::FromStr;
+
+/// Recover a master seed phrase from SLIP-39 shares and derive its key pair
+#[derive(Debug, Clone, clap::Clap)]
+pub struct CliRecoverSeedPhrase {
+    /// The shares, each one quoted as a single argument
+    shares: Vec<String>,
+    #[clap(long, default_value = "m/44'/397'/0'")]
+    seed_phrase_hd_path: slip10::BIP32Path,
+    /// The BIP39 passphrase the key pair was derived with
+    #[clap(long)]
+    passphrase: Option<String>,
+    #[clap(long, default_value = "ed25519")]
+    key_type: crate::types::key_type::KeyType,
+    /// Save the recovered key to the keychain for this account
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    /// testnet, mainnet, betanet, sandbox or an RPC URL: the keychain of the network to save the key to
+    #[clap(long, default_value = "testnet")]
+    network: String,
+}
+
+impl Default for CliRecoverSeedPhrase {
+    fn default() -> Self {
+        Self {
+            shares: vec![],
+            seed_phrase_hd_path: slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
+            passphrase: None,
+            key_type: Default::default(),
+            account_id: None,
+            network: "testnet".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecoverSeedPhrase {
+    shares: Vec<String>,
+    seed_phrase_hd_path: slip10::BIP32Path,
+    passphrase: Option<String>,
+    key_type: crate::types::key_type::KeyType,
+    account_id: Option<near_primitives::types::AccountId>,
+    network: String,
+}
+
+impl CliRecoverSeedPhrase {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args: std::collections::VecDeque<String> = self.shares.iter().cloned().collect();
+        args.push_front(self.network.to_string());
+        args.push_front("--network".to_string());
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_string());
+        }
+        args.push_front(self.key_type.to_string());
+        args.push_front("--key-type".to_string());
+        if let Some(passphrase) = &self.passphrase {
+            args.push_front(passphrase.to_string());
+            args.push_front("--passphrase".to_string());
+        }
+        args.push_front(self.seed_phrase_hd_path.to_string());
+        args.push_front("--seed-phrase-hd-path".to_string());
+        args
+    }
+}
+
+impl From<RecoverSeedPhrase> for CliRecoverSeedPhrase {
+    fn from(item: RecoverSeedPhrase) -> Self {
+        Self {
+            shares: item.shares,
+            seed_phrase_hd_path: item.seed_phrase_hd_path,
+            passphrase: item.passphrase,
+            key_type: item.key_type,
+            account_id: item.account_id,
+            network: item.network,
+        }
+    }
+}
+
+impl From<CliRecoverSeedPhrase> for RecoverSeedPhrase {
+    fn from(item: CliRecoverSeedPhrase) -> Self {
+        Self {
+            shares: item.shares,
+            seed_phrase_hd_path: item.seed_phrase_hd_path,
+            passphrase: item.passphrase,
+            key_type: item.key_type,
+            account_id: item.account_id,
+            network: item.network,
+        }
+    }
+}
+
+impl RecoverSeedPhrase {
+    /// Asks for one more share; an empty input means there are no more shares
+    fn input_share(shares_count: usize) -> Option<String> {
+        let share: String = Input::new()
+            .with_prompt(format!(
+                "Enter share #{} (leave blank when all shares are entered)",
+                shares_count + 1
+            ))
+            .allow_empty(true)
+            .interact_text()
+            .unwrap();
+        if share.trim().is_empty() {
+            None
+        } else {
+            Some(share)
+        }
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut shares = self.shares.clone();
+        if shares.is_empty() {
+            while let Some(share) = Self::input_share(shares.len()) {
+                shares.push(share);
+            }
+        }
+        let mnemonics = shares
+            .iter()
+            .map(|share| share.split_whitespace().map(str::to_string).collect())
+            .collect::<Vec<Vec<String>>>();
+        let entropy = sssmc39::combine_mnemonics(&mnemonics, "").map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to recover the seed phrase: {}", err))
+        })?;
+        let master_seed_phrase = bip39::Mnemonic::from_entropy(&entropy)?.to_string();
+
+        let key_pair_properties =
+            crate::common::generate_keypair(&crate::common::KeyDerivationParams {
+                master_seed_phrase: Some(master_seed_phrase),
+                seed_phrase_hd_path: self.seed_phrase_hd_path,
+                passphrase: self.passphrase.unwrap_or_default(),
+                key_type: self.key_type.into(),
+                ..Default::default()
+            })
+            .await?;
+        println!(
+            "\nMaster Seed Phrase: {}\nSeed Phrase HD Path: {}\nPublic Key: {}",
+            key_pair_properties.master_seed_phrase,
+            key_pair_properties.seed_phrase_hd_path.to_string(),
+            key_pair_properties.public_key_str,
+        );
+        if let Some(account_id) = self.account_id {
+            let connection_config =
+                crate::common::ConnectionConfig::from_network_name(&self.network)?;
+            crate::common::save_access_key_to_keychain(
+                Some(connection_config),
+                key_pair_properties,
+                account_id.as_ref(),
+                near_primitives::views::AccessKeyPermissionView::FullAccess,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
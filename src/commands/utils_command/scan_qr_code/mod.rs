@@ -0,0 +1,83 @@
+use dialoguer::Input;
+
+/// Reassemble a base64-encoded transaction from the QR code parts read by a scanner
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliScanQrCode {
+    parts: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScanQrCode {
+    parts: Vec<String>,
+}
+
+impl CliScanQrCode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.parts.iter().cloned().collect()
+    }
+}
+
+impl From<ScanQrCode> for CliScanQrCode {
+    fn from(scan_qr_code: ScanQrCode) -> Self {
+        Self {
+            parts: scan_qr_code.parts,
+        }
+    }
+}
+
+impl From<CliScanQrCode> for ScanQrCode {
+    fn from(item: CliScanQrCode) -> Self {
+        Self { parts: item.parts }
+    }
+}
+
+impl ScanQrCode {
+    fn input_part(collector: &crate::qr_code::FrameCollector) -> String {
+        let prompt = if collector.is_empty() {
+            "Paste the scanned QR code (any part)".to_string()
+        } else {
+            format!(
+                "Paste the next scanned QR code part (missing parts: {:?})",
+                collector.missing_parts()
+            )
+        };
+        Input::new().with_prompt(prompt).interact_text().unwrap()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut collector = crate::qr_code::FrameCollector::default();
+        for part in &self.parts {
+            collector.add(part)?;
+        }
+        while !collector.is_complete() {
+            let part = Self::input_part(&collector);
+            if let Err(err) = collector.add(&part) {
+                println!("Error: {}", err);
+            }
+        }
+        let payload = collector.into_payload()?;
+
+        if let Ok(signed_transaction) = payload.parse::<crate::common::SignedTransactionAsBase64>()
+        {
+            println!("\nSigned transaction:\n");
+            crate::common::print_transaction(signed_transaction.inner.transaction.clone());
+            println!(
+                "{:<13} {}",
+                "signature:", signed_transaction.inner.signature
+            );
+            println!("\nBase64-encoded signed transaction:\n{}", payload);
+        } else {
+            let transaction: crate::common::TransactionAsBase64 =
+                payload.parse().map_err(|err: String| {
+                    color_eyre::Report::msg(format!(
+                        "The scanned data is not a transaction: {}",
+                        err
+                    ))
+                })?;
+            println!("\nUnsigned transaction:\n");
+            crate::common::print_transaction(transaction.inner);
+            println!("\nBase64-encoded unsigned transaction:\n{}", payload);
+        }
+        Ok(())
+    }
+}
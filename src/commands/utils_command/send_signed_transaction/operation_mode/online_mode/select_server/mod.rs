@@ -11,6 +11,8 @@ pub enum CliSelectServer {
     Mainnet(self::server::CliServer),
     /// providing data to server https://rpc.betanet.near.org
     Betanet(self::server::CliServer),
+    /// providing data to the local sandbox node http://127.0.0.1:3030
+    Sandbox(self::server::CliServer),
     /// providing data to the manually specified server
     Custom(self::server::CliCustomServer),
 }
@@ -24,6 +26,8 @@ pub enum SelectServer {
     Mainnet(self::server::Server),
     #[strum_discriminants(strum(message = "Betanet"))]
     Betanet(self::server::Server),
+    #[strum_discriminants(strum(message = "Sandbox"))]
+    Sandbox(self::server::Server),
     #[strum_discriminants(strum(message = "Custom"))]
     Custom(self::server::Server),
 }
@@ -46,6 +50,11 @@ impl CliSelectServer {
                 args.push_front("betanet".to_owned());
                 args
             }
+            Self::Sandbox(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sandbox".to_owned());
+                args
+            }
             Self::Custom(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("custom".to_owned());
@@ -61,6 +70,7 @@ impl From<SelectServer> for CliSelectServer {
             SelectServer::Testnet(server) => Self::Testnet(server.into()),
             SelectServer::Mainnet(server) => Self::Mainnet(server.into()),
             SelectServer::Betanet(server) => Self::Betanet(server.into()),
+            SelectServer::Sandbox(server) => Self::Sandbox(server.into()),
             SelectServer::Custom(server) => Self::Custom(server.into()),
         }
     }
@@ -78,6 +88,9 @@ impl From<CliSelectServer> for SelectServer {
             CliSelectServer::Betanet(cli_server) => {
                 Self::Betanet(cli_server.into_server(crate::common::ConnectionConfig::Betanet))
             }
+            CliSelectServer::Sandbox(cli_server) => {
+                Self::Sandbox(cli_server.into_server(crate::common::ConnectionConfig::Sandbox))
+            }
             CliSelectServer::Custom(cli_custom_server) => {
                 Self::Custom(cli_custom_server.into_server())
             }
@@ -103,6 +116,7 @@ impl SelectServer {
             SelectServerDiscriminants::Testnet => CliSelectServer::Testnet(Default::default()),
             SelectServerDiscriminants::Mainnet => CliSelectServer::Mainnet(Default::default()),
             SelectServerDiscriminants::Betanet => CliSelectServer::Betanet(Default::default()),
+            SelectServerDiscriminants::Sandbox => CliSelectServer::Sandbox(Default::default()),
             SelectServerDiscriminants::Custom => CliSelectServer::Custom(Default::default()),
         };
         Self::from(cli_select_server)
@@ -119,6 +133,9 @@ impl SelectServer {
             SelectServer::Betanet(server) => {
                 server.process().await?;
             }
+            SelectServer::Sandbox(server) => {
+                server.process().await?;
+            }
             SelectServer::Custom(server) => {
                 server.process().await?;
             }
@@ -0,0 +1,65 @@
+use dialoguer::Input;
+
+/// Show a base64-encoded transaction (signed or not) as QR codes to move it to another device
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliShowQrCode {
+    transaction: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShowQrCode {
+    transaction: String,
+}
+
+impl CliShowQrCode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(transaction) = &self.transaction {
+            args.push_front(transaction.to_string());
+        }
+        args
+    }
+}
+
+impl From<ShowQrCode> for CliShowQrCode {
+    fn from(show_qr_code: ShowQrCode) -> Self {
+        Self {
+            transaction: Some(show_qr_code.transaction),
+        }
+    }
+}
+
+impl From<CliShowQrCode> for ShowQrCode {
+    fn from(item: CliShowQrCode) -> Self {
+        let transaction: String = match item.transaction {
+            Some(transaction) => transaction,
+            None => ShowQrCode::input_transaction(),
+        };
+        Self { transaction }
+    }
+}
+
+impl ShowQrCode {
+    fn input_transaction() -> String {
+        Input::new()
+            .with_prompt("Enter the base64-encoded transaction (signed or not)")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let transaction = self.transaction.trim();
+        if transaction
+            .parse::<crate::common::SignedTransactionAsBase64>()
+            .is_err()
+            && transaction
+                .parse::<crate::common::TransactionAsBase64>()
+                .is_err()
+        {
+            return Err(color_eyre::Report::msg(
+                "The input is neither a base64-encoded transaction nor a signed transaction",
+            ));
+        }
+        crate::qr_code::print_qr_codes(transaction)
+    }
+}
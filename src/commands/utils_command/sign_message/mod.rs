@@ -0,0 +1,610 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use near_primitives::borsh::BorshSerialize;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+/// The prefix (2^31 + 413) that keeps NEP-413 payloads from ever being valid transactions
+const NEP413_PAYLOAD_TAG: u32 = (1 << 31) + 413;
+
+/// Sign a message for a dApp to prove the ownership of an account (NEP-413)
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignMessage {
+    #[clap(long)]
+    signer_account_id: Option<near_primitives::types::AccountId>,
+    #[clap(long)]
+    message: Option<String>,
+    #[clap(long)]
+    recipient: Option<String>,
+    /// Base64-encoded 32-byte nonce; a random one is used when it is omitted
+    #[clap(long)]
+    nonce: Option<String>,
+    #[clap(long)]
+    callback_url: Option<String>,
+    #[clap(subcommand)]
+    signer: Option<CliMessageSigner>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignMessage {
+    signer_account_id: near_primitives::types::AccountId,
+    message: String,
+    recipient: String,
+    nonce: [u8; 32],
+    callback_url: Option<String>,
+    signer: MessageSigner,
+}
+
+impl CliSignMessage {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .signer
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(callback_url) = &self.callback_url {
+            args.push_front(callback_url.to_string());
+            args.push_front("--callback-url".to_string());
+        }
+        if let Some(nonce) = &self.nonce {
+            args.push_front(nonce.to_string());
+            args.push_front("--nonce".to_string());
+        }
+        if let Some(recipient) = &self.recipient {
+            args.push_front(recipient.to_string());
+            args.push_front("--recipient".to_string());
+        }
+        if let Some(message) = &self.message {
+            args.push_front(message.to_string());
+            args.push_front("--message".to_string());
+        }
+        if let Some(signer_account_id) = &self.signer_account_id {
+            args.push_front(signer_account_id.to_string());
+            args.push_front("--signer-account-id".to_string());
+        }
+        args
+    }
+}
+
+impl From<SignMessage> for CliSignMessage {
+    fn from(item: SignMessage) -> Self {
+        Self {
+            signer_account_id: Some(item.signer_account_id),
+            message: Some(item.message),
+            recipient: Some(item.recipient),
+            nonce: Some(base64::encode(&item.nonce)),
+            callback_url: item.callback_url,
+            signer: Some(item.signer.into()),
+        }
+    }
+}
+
+impl SignMessage {
+    pub fn from_cli(item: CliSignMessage) -> color_eyre::eyre::Result<Self> {
+        let signer_account_id = match item.signer_account_id {
+            Some(signer_account_id) => signer_account_id,
+            None => Input::new()
+                .with_prompt("What is the account ID of the signer?")
+                .interact_text()?,
+        };
+        let message = match item.message {
+            Some(message) => message,
+            None => Input::new()
+                .with_prompt("Enter the message to sign")
+                .interact_text()?,
+        };
+        let recipient = match item.recipient {
+            Some(recipient) => recipient,
+            None => Input::new()
+                .with_prompt("Who is the recipient of the message? (e.g. the dApp domain or contract account)")
+                .interact_text()?,
+        };
+        let nonce = match item.nonce {
+            Some(nonce) => parse_nonce(&nonce)?,
+            None => rand::random(),
+        };
+        let signer = match item.signer {
+            Some(cli_signer) => MessageSigner::from(cli_signer),
+            None => MessageSigner::choose_signer(),
+        };
+        Ok(Self {
+            signer_account_id,
+            message,
+            recipient,
+            nonce,
+            callback_url: item.callback_url,
+            signer,
+        })
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let payload = nep413_payload(
+            &self.message,
+            &self.nonce,
+            &self.recipient,
+            self.callback_url.as_deref(),
+        );
+        let (public_key, signature) = self.signer.sign(&self.signer_account_id, payload).await?;
+        let signed_message = SignedMessage {
+            account_id: self.signer_account_id,
+            public_key,
+            signature: base64::encode(signature_to_bytes(&signature)),
+            message: self.message,
+            nonce: base64::encode(&self.nonce),
+            recipient: self.recipient,
+            callback_url: self.callback_url,
+        };
+        println!("\n{}", serde_json::to_string_pretty(&signed_message)?);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliMessageSigner {
+    /// Sign the message with a private key
+    SignWithPrivateKey(CliSignWithPrivateKey),
+    /// Sign the message with the key of the account stored in the keychain
+    SignWithKeychain(CliSignWithKeychain),
+    #[cfg(feature = "ledger")]
+    /// Sign the message with a Ledger device
+    SignWithLedger(CliSignWithLedger),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum MessageSigner {
+    #[strum_discriminants(strum(message = "Sign the message with a private key"))]
+    SignWithPrivateKey(SignWithPrivateKey),
+    #[strum_discriminants(strum(message = "Sign the message with the keychain"))]
+    SignWithKeychain(SignWithKeychain),
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(message = "Sign the message with Ledger Nano S/X device"))]
+    SignWithLedger(SignWithLedger),
+}
+
+impl CliMessageSigner {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SignWithPrivateKey(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-with-private-key".to_owned());
+                args
+            }
+            Self::SignWithKeychain(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-with-keychain".to_owned());
+                args
+            }
+            #[cfg(feature = "ledger")]
+            Self::SignWithLedger(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-with-ledger".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<MessageSigner> for CliMessageSigner {
+    fn from(item: MessageSigner) -> Self {
+        match item {
+            MessageSigner::SignWithPrivateKey(sign_with_private_key) => {
+                Self::SignWithPrivateKey(sign_with_private_key.into())
+            }
+            MessageSigner::SignWithKeychain(sign_with_keychain) => {
+                Self::SignWithKeychain(sign_with_keychain.into())
+            }
+            #[cfg(feature = "ledger")]
+            MessageSigner::SignWithLedger(sign_with_ledger) => {
+                Self::SignWithLedger(sign_with_ledger.into())
+            }
+        }
+    }
+}
+
+impl From<CliMessageSigner> for MessageSigner {
+    fn from(item: CliMessageSigner) -> Self {
+        match item {
+            CliMessageSigner::SignWithPrivateKey(cli_sign_with_private_key) => {
+                Self::SignWithPrivateKey(cli_sign_with_private_key.into())
+            }
+            CliMessageSigner::SignWithKeychain(cli_sign_with_keychain) => {
+                Self::SignWithKeychain(cli_sign_with_keychain.into())
+            }
+            #[cfg(feature = "ledger")]
+            CliMessageSigner::SignWithLedger(cli_sign_with_ledger) => {
+                Self::SignWithLedger(cli_sign_with_ledger.into())
+            }
+        }
+    }
+}
+
+impl MessageSigner {
+    fn choose_signer() -> Self {
+        println!();
+        let variants = MessageSignerDiscriminants::iter().collect::<Vec<_>>();
+        let signers = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_signer = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to sign the message?")
+            .items(&signers)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_signer = match variants[selected_signer] {
+            MessageSignerDiscriminants::SignWithPrivateKey => {
+                CliMessageSigner::SignWithPrivateKey(Default::default())
+            }
+            MessageSignerDiscriminants::SignWithKeychain => {
+                CliMessageSigner::SignWithKeychain(Default::default())
+            }
+            #[cfg(feature = "ledger")]
+            MessageSignerDiscriminants::SignWithLedger => {
+                CliMessageSigner::SignWithLedger(Default::default())
+            }
+        };
+        Self::from(cli_signer)
+    }
+
+    /// Signs sha256 of the NEP-413 payload, the way the Ledger app signs the bytes it receives
+    async fn sign(
+        &self,
+        signer_account_id: &near_primitives::types::AccountId,
+        payload: Vec<u8>,
+    ) -> color_eyre::eyre::Result<(near_crypto::PublicKey, near_crypto::Signature)> {
+        let secret_key = match self {
+            Self::SignWithPrivateKey(sign_with_private_key) => {
+                sign_with_private_key.signer_private_key.clone()
+            }
+            Self::SignWithKeychain(sign_with_keychain) => {
+                sign_with_keychain.secret_key(signer_account_id)?
+            }
+            #[cfg(feature = "ledger")]
+            Self::SignWithLedger(sign_with_ledger) => return sign_with_ledger.sign(payload).await,
+        };
+        let signature = secret_key.sign(near_primitives::hash::hash(&payload).as_ref());
+        Ok((secret_key.public_key(), signature))
+    }
+}
+
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignWithPrivateKey {
+    #[clap(long)]
+    signer_private_key: Option<near_crypto::SecretKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignWithPrivateKey {
+    signer_private_key: near_crypto::SecretKey,
+}
+
+impl CliSignWithPrivateKey {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(signer_private_key) = &self.signer_private_key {
+            args.push_front(signer_private_key.to_string());
+            args.push_front("--signer-private-key".to_string());
+        }
+        args
+    }
+}
+
+impl From<SignWithPrivateKey> for CliSignWithPrivateKey {
+    fn from(item: SignWithPrivateKey) -> Self {
+        Self {
+            signer_private_key: Some(item.signer_private_key),
+        }
+    }
+}
+
+impl From<CliSignWithPrivateKey> for SignWithPrivateKey {
+    fn from(item: CliSignWithPrivateKey) -> Self {
+        let signer_private_key = match item.signer_private_key {
+            Some(signer_private_key) => signer_private_key,
+            None => Input::new()
+                .with_prompt("Enter the private key of the signer")
+                .interact_text()
+                .unwrap(),
+        };
+        Self { signer_private_key }
+    }
+}
+
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignWithKeychain {
+    /// testnet, mainnet, betanet, sandbox or the RPC URL of the network the key is stored for
+    #[clap(long)]
+    network: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignWithKeychain {
+    network: String,
+}
+
+impl CliSignWithKeychain {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+            args.push_front("--network".to_string());
+        }
+        args
+    }
+}
+
+impl From<SignWithKeychain> for CliSignWithKeychain {
+    fn from(item: SignWithKeychain) -> Self {
+        Self {
+            network: Some(item.network),
+        }
+    }
+}
+
+impl From<CliSignWithKeychain> for SignWithKeychain {
+    fn from(item: CliSignWithKeychain) -> Self {
+        let network = match item.network {
+            Some(network) => network,
+            None => Input::new()
+                .with_prompt("What network is the key stored for? (testnet, mainnet, betanet, sandbox or an RPC URL)")
+                .with_initial_text("testnet")
+                .interact_text()
+                .unwrap(),
+        };
+        Self { network }
+    }
+}
+
+impl SignWithKeychain {
+    /// NEP-413 messages are signed with a full access key of the account
+    fn secret_key(
+        &self,
+        signer_account_id: &near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+        let connection_config = crate::common::ConnectionConfig::from_network_name(&self.network)?;
+        crate::common::keychain_access_keys(&connection_config, signer_account_id.as_ref())
+            .into_iter()
+            .find(|access_key| access_key.is_full_access())
+            .map(|access_key| access_key.private_key)
+            .ok_or_else(|| {
+                color_eyre::Report::msg(format!(
+                    "There is no full access key of <{}> in the keychain",
+                    signer_account_id
+                ))
+            })
+    }
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignWithLedger {
+    #[clap(long)]
+    seed_phrase_hd_path: Option<slip10::BIP32Path>,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Debug, Clone)]
+pub struct SignWithLedger {
+    seed_phrase_hd_path: slip10::BIP32Path,
+}
+
+#[cfg(feature = "ledger")]
+impl CliSignWithLedger {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(seed_phrase_hd_path) = &self.seed_phrase_hd_path {
+            args.push_front(seed_phrase_hd_path.to_string());
+            args.push_front("--seed-phrase-hd-path".to_string());
+        }
+        args
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl From<SignWithLedger> for CliSignWithLedger {
+    fn from(item: SignWithLedger) -> Self {
+        Self {
+            seed_phrase_hd_path: Some(item.seed_phrase_hd_path),
+        }
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl From<CliSignWithLedger> for SignWithLedger {
+    fn from(item: CliSignWithLedger) -> Self {
+        let seed_phrase_hd_path = match item.seed_phrase_hd_path {
+            Some(hd_path) => hd_path,
+            None => Input::new()
+                .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
+                .with_initial_text("44'/397'/0'/0'/1'")
+                .interact_text()
+                .unwrap(),
+        };
+        Self {
+            seed_phrase_hd_path,
+        }
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl SignWithLedger {
+    /// The payload starts with the NEP-413 tag, so the device cannot be tricked into signing
+    /// a transaction with it
+    async fn sign(
+        &self,
+        payload: Vec<u8>,
+    ) -> color_eyre::eyre::Result<(near_crypto::PublicKey, near_crypto::Signature)> {
+        println!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            self.seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(self.seed_phrase_hd_path.clone())
+            .await
+            .map_err(|near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            })?;
+        println!(
+            "Confirm message signing on your Ledger device (HD Path: {})",
+            self.seed_phrase_hd_path,
+        );
+        let signature = near_ledger::sign_transaction(payload, self.seed_phrase_hd_path.clone())
+            .await
+            .map_err(|near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "Error occurred while signing the message: {:?}",
+                    near_ledger_error
+                ))
+            })?;
+        Ok((
+            near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+                public_key.to_bytes(),
+            )),
+            near_crypto::Signature::from_parts(near_crypto::KeyType::ED25519, &signature)
+                .expect("Signature is not expected to fail on deserialization"),
+        ))
+    }
+}
+
+/// The result of signing a message, in the form dApps expect it
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SignedMessage {
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+    /// Base64-encoded signature bytes
+    pub signature: String,
+    pub message: String,
+    /// Base64-encoded 32-byte nonce
+    pub nonce: String,
+    pub recipient: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+}
+
+impl SignedMessage {
+    pub fn payload_hash(&self) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        Ok(nep413_payload_hash(
+            &self.message,
+            &parse_nonce(&self.nonce)?,
+            &self.recipient,
+            self.callback_url.as_deref(),
+        ))
+    }
+
+    pub fn signature(&self) -> color_eyre::eyre::Result<near_crypto::Signature> {
+        let signature = base64::decode(&self.signature).map_err(|err| {
+            color_eyre::Report::msg(format!("The signature is not valid base64: {}", err))
+        })?;
+        near_crypto::Signature::from_parts(self.public_key.key_type(), &signature)
+            .map_err(|err| color_eyre::Report::msg(format!("The signature is invalid: {}", err)))
+    }
+}
+
+/// sha256 of the NEP-413 payload, the data the signature is made for
+fn nep413_payload_hash(
+    message: &str,
+    nonce: &[u8; 32],
+    recipient: &str,
+    callback_url: Option<&str>,
+) -> near_primitives::hash::CryptoHash {
+    near_primitives::hash::hash(&nep413_payload(message, nonce, recipient, callback_url))
+}
+
+/// The tag followed by the Borsh-serialized `{ message, nonce, recipient, callbackUrl }`
+fn nep413_payload(
+    message: &str,
+    nonce: &[u8; 32],
+    recipient: &str,
+    callback_url: Option<&str>,
+) -> Vec<u8> {
+    let mut payload = NEP413_PAYLOAD_TAG
+        .try_to_vec()
+        .expect("Payload is not expected to fail on serialization");
+    payload.extend(
+        message
+            .to_string()
+            .try_to_vec()
+            .expect("Payload is not expected to fail on serialization"),
+    );
+    payload.extend(nonce);
+    payload.extend(
+        recipient
+            .to_string()
+            .try_to_vec()
+            .expect("Payload is not expected to fail on serialization"),
+    );
+    payload.extend(
+        callback_url
+            .map(str::to_string)
+            .try_to_vec()
+            .expect("Payload is not expected to fail on serialization"),
+    );
+    payload
+}
+
+fn parse_nonce(nonce: &str) -> color_eyre::eyre::Result<[u8; 32]> {
+    let nonce = base64::decode(nonce).map_err(|err| {
+        color_eyre::Report::msg(format!("The nonce is not valid base64: {}", err))
+    })?;
+    std::convert::TryInto::try_into(nonce.as_slice())
+        .map_err(|_| color_eyre::Report::msg("The nonce must be exactly 32 bytes long"))
+}
+
+fn signature_to_bytes(signature: &near_crypto::Signature) -> Vec<u8> {
+    match signature {
+        near_crypto::Signature::ED25519(signature) => signature.to_bytes().to_vec(),
+        near_crypto::Signature::SECP256K1(signature) => {
+            <[u8; 65]>::from(signature.clone()).to_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The payload bytes are serialized by hand following NEP-413:
+    // tag (u32 LE) | message (u32 LE length + utf8) | nonce (32 bytes) | recipient | callbackUrl (Option)
+    #[test]
+    fn nep413_payload_hash_without_callback_url() {
+        let nonce: [u8; 32] =
+            std::convert::TryInto::try_into((0u8..32).collect::<Vec<u8>>().as_slice()).unwrap();
+        let payload = hex::decode(
+            "9d0100800b00000048656c6c6f204e45415221\
+             000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\
+             0c0000006578616d706c652e6e656172\
+             00",
+        )
+        .unwrap();
+        assert_eq!(
+            nep413_payload("Hello NEAR!", &nonce, "example.near", None),
+            payload
+        );
+        let payload_hash = nep413_payload_hash("Hello NEAR!", &nonce, "example.near", None);
+        assert_eq!(
+            hex::encode(payload_hash.as_ref()),
+            "56a8ca2707cf5ac61c6c672e2f77f2892f701361c96ef8fcc46b952b5976c3d5"
+        );
+    }
+
+    #[test]
+    fn nep413_payload_hash_with_callback_url() {
+        let nonce: [u8; 32] =
+            std::convert::TryInto::try_into((0u8..32).collect::<Vec<u8>>().as_slice()).unwrap();
+        assert_eq!(
+            hex::encode(
+                nep413_payload_hash(
+                    "Hello NEAR!",
+                    &nonce,
+                    "example.near",
+                    Some("http://localhost:3000")
+                )
+                .as_ref()
+            ),
+            "45408d8e97ec5a19cc71321ef4dbe13d15cd9e7d122b1f041cfadff6d9d684fe"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use dialoguer::Input;
+
+/// Split a master seed phrase into N-of-M SLIP-39 shares
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSplitSeedPhrase {
+    #[clap(long)]
+    master_seed_phrase: Option<String>,
+    /// How many shares are needed to recover the seed phrase
+    #[clap(long)]
+    threshold: Option<u8>,
+    /// How many shares to create
+    #[clap(long)]
+    shares_count: Option<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SplitSeedPhrase {
+    master_seed_phrase: String,
+    threshold: u8,
+    shares_count: u8,
+}
+
+impl CliSplitSeedPhrase {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(shares_count) = &self.shares_count {
+            args.push_front(shares_count.to_string());
+            args.push_front("--shares-count".to_string());
+        }
+        if let Some(threshold) = &self.threshold {
+            args.push_front(threshold.to_string());
+            args.push_front("--threshold".to_string());
+        }
+        if let Some(master_seed_phrase) = &self.master_seed_phrase {
+            args.push_front(master_seed_phrase.to_string());
+            args.push_front("--master-seed-phrase".to_string());
+        }
+        args
+    }
+}
+
+impl From<SplitSeedPhrase> for CliSplitSeedPhrase {
+    fn from(item: SplitSeedPhrase) -> Self {
+        Self {
+            master_seed_phrase: Some(item.master_seed_phrase),
+            threshold: Some(item.threshold),
+            shares_count: Some(item.shares_count),
+        }
+    }
+}
+
+impl From<CliSplitSeedPhrase> for SplitSeedPhrase {
+    fn from(item: CliSplitSeedPhrase) -> Self {
+        let master_seed_phrase = match item.master_seed_phrase {
+            Some(master_seed_phrase) => master_seed_phrase,
+            None => Input::new()
+                .with_prompt("Enter the master seed phrase to split")
+                .interact_text()
+                .unwrap(),
+        };
+        let shares_count = match item.shares_count {
+            Some(shares_count) => shares_count,
+            None => Input::new()
+                .with_prompt("How many shares do you want to create?")
+                .with_initial_text("5")
+                .interact_text()
+                .unwrap(),
+        };
+        let threshold = match item.threshold {
+            Some(threshold) => threshold,
+            None => Input::new()
+                .with_prompt("How many shares are needed to recover the seed phrase?")
+                .with_initial_text("3")
+                .interact_text()
+                .unwrap(),
+        };
+        Self {
+            master_seed_phrase,
+            threshold,
+            shares_count,
+        }
+    }
+}
+
+impl SplitSeedPhrase {
+    pub async fn process(self) -> crate::CliResult {
+        if self.threshold == 0 || self.threshold > self.shares_count || self.shares_count > 16 {
+            return Err(color_eyre::Report::msg(format!(
+                "Cannot split into {}-of-{} shares: the threshold must be between 1 and the number of shares (at most 16)",
+                self.threshold, self.shares_count
+            )));
+        }
+        // The BIP39 entropy is split, so the recovered seed phrase is exactly the original one
+        let entropy = bip39::Mnemonic::parse(&self.master_seed_phrase)?.to_entropy();
+        let group_shares =
+            sssmc39::generate_mnemonics(1, &[(self.threshold, self.shares_count)], &entropy, "", 0)
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to split the seed phrase: {}", err))
+                })?;
+        let shares = group_shares
+            .iter()
+            .map(|group_share| group_share.mnemonic_list())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to encode the shares: {}", err))
+            })?
+            .concat();
+        println!(
+            "\nThe seed phrase is split into {} shares; any {} of them recover it.\nStore every share separately and never keep the threshold of shares together.\n",
+            self.shares_count, self.threshold
+        );
+        for (index, share) in shares.iter().enumerate() {
+            println!("Share {}: {}", index + 1, share.join(" "));
+        }
+        Ok(())
+    }
+}
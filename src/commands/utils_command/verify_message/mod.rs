@@ -0,0 +1,152 @@
+use dialoguer::Input;
+
+/// Verify a signed message (NEP-413) and, optionally, that its key belongs to the account on chain
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliVerifyMessage {
+    /// The JSON produced by "sign-message", or the path of a file containing it
+    signed_message: Option<String>,
+    /// testnet, mainnet, betanet, sandbox or an RPC URL to check the public key on chain
+    #[clap(long)]
+    network: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyMessage {
+    signed_message: String,
+    network: Option<String>,
+}
+
+impl CliVerifyMessage {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+            args.push_front("--network".to_string());
+        }
+        if let Some(signed_message) = &self.signed_message {
+            args.push_front(signed_message.to_string());
+        }
+        args
+    }
+}
+
+impl From<VerifyMessage> for CliVerifyMessage {
+    fn from(item: VerifyMessage) -> Self {
+        Self {
+            signed_message: Some(item.signed_message),
+            network: item.network,
+        }
+    }
+}
+
+impl From<CliVerifyMessage> for VerifyMessage {
+    fn from(item: CliVerifyMessage) -> Self {
+        let signed_message = match item.signed_message {
+            Some(signed_message) => signed_message,
+            None => Input::new()
+                .with_prompt("Enter the signed message JSON or the path of a file containing it")
+                .interact_text()
+                .unwrap(),
+        };
+        let network = match item.network {
+            Some(network) => Some(network),
+            None => {
+                let network: String = Input::new()
+                    .with_prompt("To check that the key belongs to the account, enter the network (leave blank to skip)")
+                    .allow_empty(true)
+                    .interact_text()
+                    .unwrap();
+                if network.is_empty() {
+                    None
+                } else {
+                    Some(network)
+                }
+            }
+        };
+        Self {
+            signed_message,
+            network,
+        }
+    }
+}
+
+impl VerifyMessage {
+    pub async fn process(self) -> crate::CliResult {
+        let data = if self.signed_message.trim_start().starts_with('{') {
+            self.signed_message.clone()
+        } else {
+            std::fs::read_to_string(&self.signed_message).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to read the signed message from {}: {}",
+                    self.signed_message, err
+                ))
+            })?
+        };
+        let signed_message: super::sign_message::SignedMessage = serde_json::from_str(&data)
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to parse the signed message: {}", err))
+            })?;
+        if !crate::common::verify_message_signature(
+            signed_message.payload_hash()?.as_ref(),
+            true,
+            &signed_message.signature()?,
+            &signed_message.public_key,
+        ) {
+            return Err(color_eyre::Report::msg(format!(
+                "The signature does not match the message and the public key <{}>",
+                signed_message.public_key
+            )));
+        }
+        println!(
+            "\nThe message for <{}> is signed with the key <{}>.",
+            signed_message.recipient, signed_message.public_key
+        );
+
+        let network = match self.network {
+            Some(network) => network,
+            None => {
+                println!(
+                    "The key was not checked to belong to <{}>.",
+                    signed_message.account_id
+                );
+                return Ok(());
+            }
+        };
+        let connection_config = crate::common::ConnectionConfig::from_network_name(&network)?;
+        let access_key_response =
+            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+                .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                    block_reference: near_primitives::types::Finality::Final.into(),
+                    request: near_primitives::views::QueryRequest::ViewAccessKey {
+                        account_id: signed_message.account_id.clone(),
+                        public_key: signed_message.public_key.clone(),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "The key <{}> is not an access key of <{}>: {:?}",
+                        signed_message.public_key, signed_message.account_id, err
+                    ))
+                })?;
+        match access_key_response.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+                near_primitives::views::AccessKeyView {
+                    permission: near_primitives::views::AccessKeyPermissionView::FullAccess,
+                    ..
+                },
+            ) => {
+                println!(
+                    "The key is a full access key of <{}> on {}.",
+                    signed_message.account_id, network
+                );
+                Ok(())
+            }
+            // NEP-413 requires the message to be signed with a full access key
+            _ => Err(color_eyre::Report::msg(format!(
+                "The key <{}> is not a full access key of <{}>",
+                signed_message.public_key, signed_message.account_id
+            ))),
+        }
+    }
+}
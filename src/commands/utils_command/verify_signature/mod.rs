@@ -0,0 +1,422 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use near_primitives::borsh::BorshSerialize;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+/// Verify the signature of a signed transaction or of an arbitrary message
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliVerifySignature {
+    #[clap(subcommand)]
+    mode: Option<CliVerifyMode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifySignature {
+    mode: VerifyMode,
+}
+
+impl CliVerifySignature {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.mode
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<VerifySignature> for CliVerifySignature {
+    fn from(verify_signature: VerifySignature) -> Self {
+        Self {
+            mode: Some(verify_signature.mode.into()),
+        }
+    }
+}
+
+impl From<CliVerifySignature> for VerifySignature {
+    fn from(item: CliVerifySignature) -> Self {
+        let mode = match item.mode {
+            Some(cli_mode) => VerifyMode::from(cli_mode),
+            None => VerifyMode::choose_mode(),
+        };
+        Self { mode }
+    }
+}
+
+impl VerifySignature {
+    pub async fn process(self) -> crate::CliResult {
+        self.mode.process()
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliVerifyMode {
+    /// Verify the signature of a base64-encoded signed transaction
+    SignedTransaction(CliVerifySignedTransaction),
+    /// Verify the signature of an arbitrary message (ED25519 or SECP256K1)
+    Message(CliVerifyMessage),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum VerifyMode {
+    #[strum_discriminants(strum(message = "Verify the signature of a signed transaction"))]
+    SignedTransaction(VerifySignedTransaction),
+    #[strum_discriminants(strum(
+        message = "Verify a message, its signature and the public key of the signer"
+    ))]
+    Message(VerifyMessage),
+}
+
+impl CliVerifyMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SignedTransaction(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("signed-transaction".to_owned());
+                args
+            }
+            Self::Message(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("message".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<VerifyMode> for CliVerifyMode {
+    fn from(mode: VerifyMode) -> Self {
+        match mode {
+            VerifyMode::SignedTransaction(verify_signed_transaction) => {
+                Self::SignedTransaction(verify_signed_transaction.into())
+            }
+            VerifyMode::Message(verify_message) => Self::Message(verify_message.into()),
+        }
+    }
+}
+
+impl From<CliVerifyMode> for VerifyMode {
+    fn from(item: CliVerifyMode) -> Self {
+        match item {
+            CliVerifyMode::SignedTransaction(cli_verify_signed_transaction) => {
+                Self::SignedTransaction(cli_verify_signed_transaction.into())
+            }
+            CliVerifyMode::Message(cli_verify_message) => Self::Message(cli_verify_message.into()),
+        }
+    }
+}
+
+impl VerifyMode {
+    pub fn choose_mode() -> Self {
+        println!();
+        let variants = VerifyModeDiscriminants::iter().collect::<Vec<_>>();
+        let modes = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_mode = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to verify?")
+            .items(&modes)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_mode = match variants[selected_mode] {
+            VerifyModeDiscriminants::SignedTransaction => {
+                CliVerifyMode::SignedTransaction(Default::default())
+            }
+            VerifyModeDiscriminants::Message => CliVerifyMode::Message(Default::default()),
+        };
+        Self::from(cli_mode)
+    }
+
+    pub fn process(self) -> crate::CliResult {
+        match self {
+            Self::SignedTransaction(verify_signed_transaction) => {
+                verify_signed_transaction.process()
+            }
+            Self::Message(verify_message) => verify_message.process(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliVerifySignedTransaction {
+    signed_transaction: Option<crate::common::SignedTransactionAsBase64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifySignedTransaction {
+    signed_transaction: near_primitives::transaction::SignedTransaction,
+}
+
+impl CliVerifySignedTransaction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(signed_transaction) = &self.signed_transaction {
+            args.push_front(near_primitives::serialize::to_base64(
+                signed_transaction
+                    .inner
+                    .try_to_vec()
+                    .expect("Transaction is not expected to fail on serialization"),
+            ));
+        }
+        args
+    }
+}
+
+impl From<VerifySignedTransaction> for CliVerifySignedTransaction {
+    fn from(item: VerifySignedTransaction) -> Self {
+        Self {
+            signed_transaction: Some(crate::common::SignedTransactionAsBase64 {
+                inner: item.signed_transaction,
+            }),
+        }
+    }
+}
+
+impl From<CliVerifySignedTransaction> for VerifySignedTransaction {
+    fn from(item: CliVerifySignedTransaction) -> Self {
+        let signed_transaction = match item.signed_transaction {
+            Some(signed_transaction) => signed_transaction.inner,
+            None => VerifySignedTransaction::input_signed_transaction(),
+        };
+        Self { signed_transaction }
+    }
+}
+
+impl VerifySignedTransaction {
+    fn input_signed_transaction() -> near_primitives::transaction::SignedTransaction {
+        let input: crate::common::SignedTransactionAsBase64 = Input::new()
+            .with_prompt("Enter a signed transaction as base64-encoded string")
+            .interact_text()
+            .unwrap();
+        input.inner
+    }
+
+    pub fn process(self) -> crate::CliResult {
+        println!("\nSigned transaction:\n");
+        crate::common::print_transaction(self.signed_transaction.transaction.clone());
+        println!("{:<13} {}", "signature:", self.signed_transaction.signature);
+        crate::common::verify_transaction_signature(&self.signed_transaction)?;
+        println!("\nThe signature is valid.");
+        Ok(())
+    }
+}
+
+/// How the message to verify is written on the command line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageEncoding {
+    Utf8,
+    Hex,
+    Base64,
+    Base58,
+}
+
+impl MessageEncoding {
+    const ALL: [Self; 4] = [Self::Utf8, Self::Hex, Self::Base64, Self::Base58];
+
+    fn decode(&self, message: &str) -> color_eyre::eyre::Result<Vec<u8>> {
+        match self {
+            Self::Utf8 => Ok(message.as_bytes().to_vec()),
+            Self::Hex => hex::decode(message).map_err(|err| {
+                color_eyre::Report::msg(format!("The message is not valid hex: {}", err))
+            }),
+            Self::Base64 => base64::decode(message).map_err(|err| {
+                color_eyre::Report::msg(format!("The message is not valid base64: {}", err))
+            }),
+            Self::Base58 => bs58::decode(message).into_vec().map_err(|err| {
+                color_eyre::Report::msg(format!("The message is not valid base58: {}", err))
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for MessageEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Utf8 => write!(f, "utf8"),
+            Self::Hex => write!(f, "hex"),
+            Self::Base64 => write!(f, "base64"),
+            Self::Base58 => write!(f, "base58"),
+        }
+    }
+}
+
+impl std::str::FromStr for MessageEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|encoding| encoding.to_string() == s)
+            .ok_or_else(|| {
+                format!(
+                    "<{}> is not a message encoding, use utf8, hex, base64 or base58",
+                    s
+                )
+            })
+    }
+}
+
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliVerifyMessage {
+    #[clap(long)]
+    message: Option<String>,
+    /// How the message is encoded: utf8 (default), hex, base64 or base58
+    #[clap(long)]
+    message_encoding: Option<MessageEncoding>,
+    /// The message is the 32-byte digest the SECP256K1 signature is made for, rather than the
+    /// data whose sha256 hash is signed
+    #[clap(long)]
+    message_is_digest: bool,
+    #[clap(long)]
+    signature: Option<near_crypto::Signature>,
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyMessage {
+    message: String,
+    message_encoding: MessageEncoding,
+    message_is_digest: bool,
+    signature: near_crypto::Signature,
+    public_key: near_crypto::PublicKey,
+}
+
+impl CliVerifyMessage {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(public_key) = &self.public_key {
+            args.push_front(public_key.to_string());
+            args.push_front("--public-key".to_string());
+        }
+        if let Some(signature) = &self.signature {
+            args.push_front(signature.to_string());
+            args.push_front("--signature".to_string());
+        }
+        if self.message_is_digest {
+            args.push_front("--message-is-digest".to_string());
+        }
+        if let Some(message_encoding) = &self.message_encoding {
+            args.push_front(message_encoding.to_string());
+            args.push_front("--message-encoding".to_string());
+        }
+        if let Some(message) = &self.message {
+            args.push_front(message.to_string());
+            args.push_front("--message".to_string());
+        }
+        args
+    }
+}
+
+impl From<VerifyMessage> for CliVerifyMessage {
+    fn from(item: VerifyMessage) -> Self {
+        Self {
+            message: Some(item.message),
+            message_encoding: Some(item.message_encoding),
+            message_is_digest: item.message_is_digest,
+            signature: Some(item.signature),
+            public_key: Some(item.public_key),
+        }
+    }
+}
+
+impl From<CliVerifyMessage> for VerifyMessage {
+    fn from(item: CliVerifyMessage) -> Self {
+        let (message, message_encoding) = match item.message {
+            Some(message) => (
+                message,
+                item.message_encoding.unwrap_or(MessageEncoding::Utf8),
+            ),
+            None => {
+                let message_encoding = match item.message_encoding {
+                    Some(message_encoding) => message_encoding,
+                    None => VerifyMessage::input_message_encoding(),
+                };
+                let message = Input::new()
+                    .with_prompt("Enter the message")
+                    .interact_text()
+                    .unwrap();
+                (message, message_encoding)
+            }
+        };
+        let signature = match item.signature {
+            Some(signature) => signature,
+            None => Input::new()
+                .with_prompt("Enter the signature")
+                .interact_text()
+                .unwrap(),
+        };
+        let public_key = match item.public_key {
+            Some(public_key) => public_key,
+            None => Input::new()
+                .with_prompt("Enter the public key of the signer")
+                .interact_text()
+                .unwrap(),
+        };
+        let message_is_digest = match public_key {
+            near_crypto::PublicKey::SECP256K1(_)
+                if item.message.is_none() && !item.message_is_digest =>
+            {
+                VerifyMessage::input_message_is_digest()
+            }
+            _ => item.message_is_digest,
+        };
+        Self {
+            message,
+            message_encoding,
+            message_is_digest,
+            signature,
+            public_key,
+        }
+    }
+}
+
+impl VerifyMessage {
+    fn input_message_encoding() -> MessageEncoding {
+        let select_encoding = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How is the message encoded?")
+            .items(&MessageEncoding::ALL)
+            .default(0)
+            .interact()
+            .unwrap();
+        MessageEncoding::ALL[select_encoding]
+    }
+
+    fn input_message_is_digest() -> bool {
+        let select_message_kind = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What was signed with the SECP256K1 key?")
+            .items(&[
+                "The sha256 hash of the message",
+                "The message itself, a 32-byte digest",
+            ])
+            .default(0)
+            .interact()
+            .unwrap();
+        select_message_kind == 1
+    }
+
+    pub fn process(self) -> crate::CliResult {
+        let message = self.message_encoding.decode(&self.message)?;
+        if self.message_is_digest && message.len() != 32 {
+            return Err(color_eyre::Report::msg(format!(
+                "The message is {} bytes long, but a digest must be 32 bytes",
+                message.len()
+            )));
+        }
+        if !crate::common::verify_message_signature(
+            &message,
+            self.message_is_digest,
+            &self.signature,
+            &self.public_key,
+        ) {
+            return Err(color_eyre::Report::msg(format!(
+                "The signature does not match the message and the public key <{}>",
+                self.public_key
+            )));
+        }
+        println!("\nThe signature is valid.");
+        Ok(())
+    }
+}
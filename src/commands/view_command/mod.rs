@@ -1,8 +1,11 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod view_access_key_audit;
 mod view_account;
 mod view_contract_code;
 mod view_contract_state;
+mod view_contract_state_diff;
+mod view_contract_storage_usage;
 mod view_nonce;
 mod view_recent_block_hash;
 mod view_transaction_status;
@@ -28,12 +31,21 @@ pub enum QueryRequest {
     #[strum_discriminants(strum(message = "View properties for an account"))]
     /// View properties for an account
     AccountSummary(self::view_account::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Audit the access keys of an account"))]
+    /// Audit the access keys of an account: permissions, allowances and the keys held locally
+    AccessKeyAudit(self::view_access_key_audit::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a contract code"))]
     /// View a contract code
     ContractCode(self::view_contract_code::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a contract state"))]
     /// View a contract state
     ContractState(self::view_contract_state::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View contract state changes between two blocks"))]
+    /// View contract state changes between two blocks
+    ContractStateDiff(self::view_contract_state_diff::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View contract storage usage by key prefix"))]
+    /// View contract storage usage by key prefix
+    ContractStorageUsage(self::view_contract_storage_usage::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a transaction status"))]
     /// View a transaction status
     Transaction(self::view_transaction_status::operation_mode::OperationMode),
@@ -49,8 +61,11 @@ impl QueryRequest {
     pub async fn process(self) -> crate::CliResult {
         match self {
             QueryRequest::AccountSummary(operation_mode) => operation_mode.process().await,
+            QueryRequest::AccessKeyAudit(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractCode(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractState(operation_mode) => operation_mode.process().await,
+            QueryRequest::ContractStateDiff(operation_mode) => operation_mode.process().await,
+            QueryRequest::ContractStorageUsage(operation_mode) => operation_mode.process().await,
             QueryRequest::Transaction(operation_mode) => operation_mode.process().await,
             QueryRequest::Nonce(operation_mode) => operation_mode.process().await,
             QueryRequest::RecentBlockHash(operation_mode) => operation_mode.process().await,
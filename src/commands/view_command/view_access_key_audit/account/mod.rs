@@ -0,0 +1,74 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewAccessKeyAuditCommandNetworkContext)]
+pub struct Account {
+    account_id: crate::types::account_id::AccountId,
+}
+
+impl Account {
+    fn input_account_id(
+        context: &super::operation_mode::online_mode::select_server::ViewAccessKeyAuditCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        let connection_config = context.connection_config.clone();
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("Which account do you want to audit the access keys of?")
+                .interact_text()?;
+            if let Some(_) =
+                crate::common::get_account_state(&connection_config, account_id.clone().into())?
+            {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            };
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let account_id: near_primitives::types::AccountId = self.account_id.into();
+        let resp = near_jsonrpc_client::JsonRpcClient::connect(
+            network_connection_config.rpc_url().as_str(),
+        )
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                account_id: account_id.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view key list: {:?}",
+                err
+            ))
+        })?;
+        let access_key_list = match resp.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(result) => {
+                result
+            }
+            _ => return Err(color_eyre::Report::msg(format!("Error call result"))),
+        };
+
+        let local_public_keys: Vec<near_crypto::PublicKey> =
+            crate::common::keychain_access_keys(&network_connection_config, account_id.as_ref())
+                .into_iter()
+                .map(|access_key| access_key.public_key)
+                .collect();
+        let keys = access_key_list
+            .keys
+            .into_iter()
+            .map(|access_key| super::AccessKeyAudit {
+                is_held_locally: local_public_keys.contains(&access_key.public_key),
+                public_key: access_key.public_key,
+                nonce: access_key.access_key.nonce,
+                permission: access_key.access_key.permission,
+            })
+            .collect::<Vec<_>>();
+        super::display_access_key_audit(&account_id, &keys);
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+mod account;
+pub mod operation_mode;
+
+/// A situation worth the attention of the account owner
+#[derive(Debug, Clone, PartialEq)]
+enum Risk {
+    /// Nobody may be able to use the key, or somebody else holds it
+    FullAccessKeyNotHeldLocally,
+    /// The key can spend the account balance on gas without a limit
+    UnlimitedAllowance,
+}
+
+impl std::fmt::Display for Risk {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::FullAccessKeyNotHeldLocally => write!(
+                f,
+                "full access key whose private key is not in the local keychain"
+            ),
+            Self::UnlimitedAllowance => {
+                write!(f, "function call key with an unlimited allowance")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AccessKeyAudit {
+    public_key: near_crypto::PublicKey,
+    nonce: u64,
+    permission: near_primitives::views::AccessKeyPermissionView,
+    is_held_locally: bool,
+}
+
+impl AccessKeyAudit {
+    fn is_full_access(&self) -> bool {
+        matches!(
+            self.permission,
+            near_primitives::views::AccessKeyPermissionView::FullAccess
+        )
+    }
+
+    fn risks(&self) -> Vec<Risk> {
+        match &self.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess
+                if !self.is_held_locally =>
+            {
+                vec![Risk::FullAccessKeyNotHeldLocally]
+            }
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance: None,
+                ..
+            } => vec![Risk::UnlimitedAllowance],
+            _ => vec![],
+        }
+    }
+}
+
+fn display_access_key_audit(
+    account_id: &near_primitives::types::AccountId,
+    keys: &[AccessKeyAudit],
+) {
+    println!("Number of access keys: {}", keys.len());
+    for (index, key) in keys.iter().enumerate() {
+        println!(
+            "{: >4}. {} (nonce: {})",
+            index + 1,
+            key.public_key,
+            key.nonce
+        );
+        match &key.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => {
+                println!("      permission: full access");
+            }
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id,
+                method_names,
+            } => {
+                println!("      permission: function call");
+                println!("      receiver: {}", receiver_id);
+                if method_names.is_empty() {
+                    println!("      methods: any");
+                } else {
+                    println!("      methods: {}", method_names.join(", "));
+                }
+                match allowance {
+                    Some(amount) => println!(
+                        "      remaining allowance: {}",
+                        crate::common::NearBalance::from_yoctonear(*amount)
+                    ),
+                    None => println!("      remaining allowance: unlimited"),
+                }
+            }
+        }
+        println!(
+            "      private key in the local keychain: {}",
+            if key.is_held_locally { "yes" } else { "no" }
+        );
+        for risk in key.risks() {
+            println!("      WARNING: {}", risk);
+        }
+    }
+    if !keys.iter().any(AccessKeyAudit::is_full_access) {
+        println!(
+            "\nWARNING: <{}> has no full access key, the account is locked",
+            account_id
+        );
+    }
+}
@@ -1,3 +1,75 @@
 mod block_id;
 pub mod operation_mode;
 mod sender;
+
+/// Which part of the contract state to show and how to decode it.
+/// `from_key` and `limit` page through the state which is downloaded with a single `ViewState`
+/// query for the prefix, so a contract state above the RPC size limit has to be narrowed down
+/// with a longer prefix.
+#[derive(Debug, Clone)]
+pub struct ContractStateViewOptions {
+    pub prefix: crate::types::store_key::StoreKey,
+    pub from_key: Option<crate::types::store_key::StoreKey>,
+    pub limit: Option<u64>,
+    pub key_format: crate::types::state_format::StateFormat,
+    pub value_format: crate::types::state_format::StateFormat,
+    pub output_file: Option<crate::types::path_buf::PathBuf>,
+}
+
+async fn display_contract_state(
+    contract_account_id: near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+    options: ContractStateViewOptions,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    let contract_state = crate::common::fetch_contract_state(
+        &network_connection_config,
+        contract_account_id.clone(),
+        options.prefix.into(),
+        block_reference,
+    )
+    .await?;
+    let mut entries = match options.from_key {
+        Some(from_key) => contract_state.range(from_key.0..),
+        None => contract_state.range::<Vec<u8>, _>(..),
+    };
+    let limit = options.limit.map_or(usize::MAX, |limit| limit as usize);
+    let page: Vec<(&Vec<u8>, &Vec<u8>)> = entries.by_ref().take(limit).collect();
+    let next_key = entries.next().map(|(key, _)| key.clone());
+
+    let output: String = page
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{}: {}\n",
+                options.key_format.decode(key),
+                options.value_format.decode(value)
+            )
+        })
+        .collect();
+    println!(
+        "\nContract state of <{}> ({} of {} entries):",
+        contract_account_id,
+        page.len(),
+        contract_state.len()
+    );
+    match options.output_file {
+        Some(output_file) => {
+            std::fs::write(&output_file.0, output).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to write the contract state to {}: {}",
+                    output_file, err
+                ))
+            })?;
+            println!("The contract state was saved to {}", output_file);
+        }
+        None => print!("{}", output),
+    }
+    if let Some(next_key) = next_key {
+        println!(
+            "\nThere are more entries. To view the next page, add: --from-key {}",
+            crate::types::store_key::StoreKey(next_key)
+        );
+    }
+    Ok(())
+}
@@ -5,6 +5,24 @@ use dialoguer::Input;
 #[interactive_clap(skip_default_from_cli)]
 pub struct Sender {
     pub sender_account_id: crate::types::account_id::AccountId,
+    ///Only show the keys starting with this prefix (utf8:<text>, hex:<hex> or base64:<base64>)
+    #[interactive_clap(long)]
+    prefix: Option<crate::types::store_key::StoreKey>,
+    ///Start the listing from this key (inclusive); the whole state under the prefix is still downloaded
+    #[interactive_clap(long)]
+    from_key: Option<crate::types::store_key::StoreKey>,
+    ///Show at most this number of entries; the whole state under the prefix is still downloaded
+    #[interactive_clap(long)]
+    limit: Option<u64>,
+    ///Decode the keys as utf8, hex, base64, json or borsh:<schema> (default: utf8)
+    #[interactive_clap(long)]
+    key_format: Option<crate::types::state_format::StateFormat>,
+    ///Decode the values as utf8, hex, base64, json or borsh:<schema> (default: base64)
+    #[interactive_clap(long)]
+    value_format: Option<crate::types::state_format::StateFormat>,
+    ///Write the contract state to this file instead of the terminal
+    #[interactive_clap(long)]
+    output_file: Option<crate::types::path_buf::PathBuf>,
     #[interactive_clap(subcommand)]
     selected_block_id: super::block_id::BlockId,
 }
@@ -31,6 +49,25 @@ impl Sender {
             },
             None => Self::input_sender_account_id(&context)?,
         };
+        let (prefix, from_key, limit, key_format, value_format, output_file) =
+            match optional_clap_variant.clone() {
+                Some(clap_variant) => (
+                    clap_variant.prefix,
+                    clap_variant.from_key,
+                    clap_variant.limit,
+                    clap_variant.key_format,
+                    clap_variant.value_format,
+                    clap_variant.output_file,
+                ),
+                None => (
+                    Self::input_prefix(&context)?,
+                    Self::input_from_key(&context)?,
+                    Self::input_limit(&context)?,
+                    Self::input_key_format(&context)?,
+                    Self::input_value_format(&context)?,
+                    Self::input_output_file(&context)?,
+                ),
+            };
         let selected_block_id: super::block_id::BlockId = match optional_clap_variant
             .and_then(|clap_variant| clap_variant.selected_block_id)
         {
@@ -39,6 +76,12 @@ impl Sender {
         };
         Ok(Self {
             sender_account_id,
+            prefix,
+            from_key,
+            limit,
+            key_format,
+            value_format,
+            output_file,
             selected_block_id,
         })
     }
@@ -63,12 +106,97 @@ impl Sender {
         }
     }
 
+    fn input_prefix(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::store_key::StoreKey>> {
+        crate::types::store_key::input_key_prefix(
+            "Do you want to filter the contract state by a key prefix?",
+            "Yes, I want to view only the keys starting with a prefix",
+            "No, I want to view the whole contract state",
+        )
+    }
+
+    fn input_from_key(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::store_key::StoreKey>> {
+        let from_key: String = Input::new()
+            .with_prompt(
+                "Start the listing from this key (leave blank to start from the first key)",
+            )
+            .allow_empty(true)
+            .interact_text()?;
+        if from_key.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(from_key.parse()?))
+    }
+
+    fn input_limit(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<u64>> {
+        let limit: String = Input::new()
+            .with_prompt("How many entries do you want to view? (leave blank to view all of them)")
+            .allow_empty(true)
+            .interact_text()?;
+        if limit.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(limit.parse().map_err(|err| {
+            color_eyre::Report::msg(format!("Invalid number of entries <{}>: {}", limit, err))
+        })?))
+    }
+
+    fn input_key_format(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::state_format::StateFormat>> {
+        crate::types::state_format::input_state_format(
+            "keys",
+            &["utf8", "hex", "base64", "json", "borsh"],
+        )
+    }
+
+    fn input_value_format(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::state_format::StateFormat>> {
+        crate::types::state_format::input_state_format(
+            "values",
+            &["base64", "utf8", "hex", "json", "borsh"],
+        )
+    }
+
+    fn input_output_file(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::path_buf::PathBuf>> {
+        let output_file: String = Input::new()
+            .with_prompt("Write the contract state to this file (leave blank to print it)")
+            .allow_empty(true)
+            .interact_text()?;
+        if output_file.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(crate::types::path_buf::PathBuf(output_file.into())))
+    }
+
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
+        let options = super::ContractStateViewOptions {
+            prefix: self.prefix.unwrap_or_default(),
+            from_key: self.from_key,
+            limit: self.limit,
+            key_format: self
+                .key_format
+                .unwrap_or(crate::types::state_format::StateFormat::Utf8),
+            value_format: self.value_format.unwrap_or_default(),
+            output_file: self.output_file,
+        };
         self.selected_block_id
-            .process(self.sender_account_id.into(), network_connection_config)
+            .process(
+                self.sender_account_id.into(),
+                options,
+                network_connection_config,
+            )
             .await
     }
 }
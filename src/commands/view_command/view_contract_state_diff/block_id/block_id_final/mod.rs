@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext)]
+pub struct BlockIdFinal {
+    #[interactive_clap(subcommand)]
+    pub to_block: super::to_block_id::ToBlockId,
+}
@@ -0,0 +1,33 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext)]
+pub struct BlockIdHeight {
+    block_id_height: near_primitives::types::BlockHeight,
+    #[interactive_clap(subcommand)]
+    to_block: super::to_block_id::ToBlockId,
+}
+
+impl BlockIdHeight {
+    pub fn input_block_id_height(
+        _context: &super::super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<near_primitives::types::BlockHeight> {
+        Ok(Input::new()
+            .with_prompt("Type the block ID height to compare from")
+            .interact_text()?)
+    }
+
+    pub fn block_reference(
+        self,
+    ) -> (
+        near_primitives::types::BlockReference,
+        super::to_block_id::ToBlockId,
+    ) {
+        (
+            near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Height(self.block_id_height),
+            ),
+            self.to_block,
+        )
+    }
+}
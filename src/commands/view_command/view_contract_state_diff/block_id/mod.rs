@@ -0,0 +1,48 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod block_id_final;
+mod block_id_hash;
+mod block_id_height;
+mod to_block_id;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext)]
+///Choose the block to compare from
+pub enum BlockId {
+    #[strum_discriminants(strum(message = "Compare from final block"))]
+    /// Specify a block ID final to compare from
+    FromFinalBlock(self::block_id_final::BlockIdFinal),
+    #[strum_discriminants(strum(message = "Compare from block height"))]
+    /// Specify a block ID height to compare from
+    FromBlockHeight(self::block_id_height::BlockIdHeight),
+    #[strum_discriminants(strum(message = "Compare from block hash"))]
+    /// Specify a block ID hash to compare from
+    FromBlockHash(self::block_id_hash::BlockIdHash),
+}
+
+impl BlockId {
+    pub async fn process(
+        self,
+        contract_account_id: near_primitives::types::AccountId,
+        options: super::ContractStateDiffOptions,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let (from_block_reference, to_block) = match self {
+            Self::FromFinalBlock(block_id_final) => (
+                near_primitives::types::Finality::Final.into(),
+                block_id_final.to_block,
+            ),
+            Self::FromBlockHeight(block_id_height) => block_id_height.block_reference(),
+            Self::FromBlockHash(block_id_hash) => block_id_hash.block_reference(),
+        };
+        to_block
+            .process(
+                contract_account_id,
+                from_block_reference,
+                options,
+                network_connection_config,
+            )
+            .await
+    }
+}
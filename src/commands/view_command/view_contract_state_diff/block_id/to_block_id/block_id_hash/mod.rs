@@ -0,0 +1,23 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::super::super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext)]
+pub struct BlockIdHash {
+    block_id_hash: crate::types::crypto_hash::CryptoHash,
+}
+
+impl BlockIdHash {
+    pub fn input_block_id_hash(
+        _context: &super::super::super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::crypto_hash::CryptoHash> {
+        Ok(Input::new()
+            .with_prompt("Type the block ID hash to compare to")
+            .interact_text()?)
+    }
+
+    pub fn block_reference(self) -> near_primitives::types::BlockReference {
+        near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Hash(
+            self.block_id_hash.into(),
+        ))
+    }
+}
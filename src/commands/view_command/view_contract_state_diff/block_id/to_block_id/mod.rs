@@ -0,0 +1,45 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod block_id_hash;
+mod block_id_height;
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap_derive::InteractiveClap)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[interactive_clap(context = super::super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext)]
+///Choose the block to compare to
+pub enum ToBlockId {
+    #[strum_discriminants(strum(message = "Compare to final block"))]
+    /// Specify a block ID final to compare to
+    ToFinalBlock,
+    #[strum_discriminants(strum(message = "Compare to block height"))]
+    /// Specify a block ID height to compare to
+    ToBlockHeight(self::block_id_height::BlockIdHeight),
+    #[strum_discriminants(strum(message = "Compare to block hash"))]
+    /// Specify a block ID hash to compare to
+    ToBlockHash(self::block_id_hash::BlockIdHash),
+}
+
+impl ToBlockId {
+    pub async fn process(
+        self,
+        contract_account_id: near_primitives::types::AccountId,
+        from_block_reference: near_primitives::types::BlockReference,
+        options: super::super::ContractStateDiffOptions,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        println!();
+        let to_block_reference = match self {
+            Self::ToBlockHeight(block_id_height) => block_id_height.block_reference(),
+            Self::ToBlockHash(block_id_hash) => block_id_hash.block_reference(),
+            Self::ToFinalBlock => near_primitives::types::Finality::Final.into(),
+        };
+        super::super::display_contract_state_diff(
+            contract_account_id,
+            from_block_reference,
+            to_block_reference,
+            options,
+            network_connection_config,
+        )
+        .await
+    }
+}
@@ -0,0 +1,137 @@
+use dialoguer::Input;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct Contract {
+    pub contract_account_id: crate::types::account_id::AccountId,
+    ///Only compare the keys starting with this prefix (utf8:<text>, hex:<hex> or base64:<base64>)
+    #[interactive_clap(long)]
+    prefix: Option<crate::types::store_key::StoreKey>,
+    ///Decode the keys as utf8, hex, base64, json or borsh:<schema> (default: utf8)
+    #[interactive_clap(long)]
+    key_format: Option<crate::types::state_format::StateFormat>,
+    ///Decode the values as utf8, hex, base64, json or borsh:<schema> (default: base64)
+    #[interactive_clap(long)]
+    value_format: Option<crate::types::state_format::StateFormat>,
+    #[interactive_clap(subcommand)]
+    from_block: super::block_id::BlockId,
+}
+
+impl Contract {
+    pub fn from_cli(
+        optional_clap_variant: Option<<Contract as interactive_clap::ToCli>::CliVariant>,
+        context: super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let connection_config = context.connection_config.clone();
+        let contract_account_id = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.contract_account_id)
+        {
+            Some(contract_account_id) => match crate::common::get_account_state(
+                &connection_config,
+                contract_account_id.clone().into(),
+            )? {
+                Some(_) => contract_account_id,
+                None => {
+                    println!("Contract <{}> doesn't exist", contract_account_id);
+                    Self::input_contract_account_id(&context)?
+                }
+            },
+            None => Self::input_contract_account_id(&context)?,
+        };
+        let (prefix, key_format, value_format) = match optional_clap_variant.clone() {
+            Some(clap_variant) => (
+                clap_variant.prefix,
+                clap_variant.key_format,
+                clap_variant.value_format,
+            ),
+            None => (
+                Self::input_prefix(&context)?,
+                Self::input_key_format(&context)?,
+                Self::input_value_format(&context)?,
+            ),
+        };
+        let from_block: super::block_id::BlockId = match optional_clap_variant
+            .and_then(|clap_variant| clap_variant.from_block)
+        {
+            Some(cli_block_id) => super::block_id::BlockId::from_cli(Some(cli_block_id), context)?,
+            None => super::block_id::BlockId::choose_variant(context)?,
+        };
+        Ok(Self {
+            contract_account_id,
+            prefix,
+            key_format,
+            value_format,
+            from_block,
+        })
+    }
+}
+
+impl Contract {
+    pub fn input_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        let connection_config = context.connection_config.clone();
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What contract state do you want to compare?")
+                .interact_text()?;
+            if let Some(_) =
+                crate::common::get_account_state(&connection_config, account_id.clone().into())?
+            {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            };
+        }
+    }
+
+    fn input_prefix(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::store_key::StoreKey>> {
+        crate::types::store_key::input_key_prefix(
+            "Do you want to compare only the keys starting with a prefix?",
+            "Yes, I want to compare only the keys starting with a prefix",
+            "No, I want to compare the whole contract state",
+        )
+    }
+
+    fn input_key_format(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::state_format::StateFormat>> {
+        crate::types::state_format::input_state_format(
+            "keys",
+            &["utf8", "hex", "base64", "json", "borsh"],
+        )
+    }
+
+    fn input_value_format(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStateDiffCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::state_format::StateFormat>> {
+        crate::types::state_format::input_state_format(
+            "values",
+            &["base64", "utf8", "hex", "json", "borsh"],
+        )
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let options = super::ContractStateDiffOptions {
+            prefix: self.prefix.unwrap_or_default(),
+            key_format: self
+                .key_format
+                .unwrap_or(crate::types::state_format::StateFormat::Utf8),
+            value_format: self.value_format.unwrap_or_default(),
+        };
+        self.from_block
+            .process(
+                self.contract_account_id.into(),
+                options,
+                network_connection_config,
+            )
+            .await
+    }
+}
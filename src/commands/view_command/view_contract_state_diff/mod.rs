@@ -0,0 +1,178 @@
+mod block_id;
+mod contract;
+pub mod operation_mode;
+
+/// Which part of the contract state to compare and how to decode it
+#[derive(Debug, Clone)]
+pub struct ContractStateDiffOptions {
+    pub prefix: crate::types::store_key::StoreKey,
+    pub key_format: crate::types::state_format::StateFormat,
+    pub value_format: crate::types::state_format::StateFormat,
+}
+
+async fn display_contract_state_diff(
+    contract_account_id: near_primitives::types::AccountId,
+    from_block_reference: near_primitives::types::BlockReference,
+    to_block_reference: near_primitives::types::BlockReference,
+    options: ContractStateDiffOptions,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    let prefix: Vec<u8> = options.prefix.into();
+    let from_block = display_block_reference(&from_block_reference);
+    let to_block = display_block_reference(&to_block_reference);
+    let old_state = crate::common::fetch_contract_state(
+        &network_connection_config,
+        contract_account_id.clone(),
+        prefix.clone(),
+        from_block_reference,
+    )
+    .await?;
+    let new_state = crate::common::fetch_contract_state(
+        &network_connection_config,
+        contract_account_id.clone(),
+        prefix,
+        to_block_reference,
+    )
+    .await?;
+
+    let changes = diff_contract_state(&old_state, &new_state);
+    println!(
+        "Contract state changes of <{}> from block {} to block {}:",
+        contract_account_id, from_block, to_block
+    );
+    let (mut added, mut removed, mut changed) = (0, 0, 0);
+    for change in changes {
+        match change {
+            StateChange::Added { key, value } => {
+                added += 1;
+                println!(
+                    "+ {}: {}",
+                    options.key_format.decode(key),
+                    options.value_format.decode(value)
+                );
+            }
+            StateChange::Removed { key, value } => {
+                removed += 1;
+                println!(
+                    "- {}: {}",
+                    options.key_format.decode(key),
+                    options.value_format.decode(value)
+                );
+            }
+            StateChange::Changed {
+                key,
+                old_value,
+                new_value,
+            } => {
+                changed += 1;
+                println!(
+                    "~ {}: {} -> {}",
+                    options.key_format.decode(key),
+                    options.value_format.decode(old_value),
+                    options.value_format.decode(new_value)
+                );
+            }
+        }
+    }
+    println!(
+        "\n{} added, {} removed, {} changed",
+        added, removed, changed
+    );
+    Ok(())
+}
+
+fn display_block_reference(block_reference: &near_primitives::types::BlockReference) -> String {
+    match block_reference {
+        near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Height(block_height),
+        ) => block_height.to_string(),
+        near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Hash(
+            block_hash,
+        )) => block_hash.to_string(),
+        _ => "final".to_string(),
+    }
+}
+
+/// A difference of a single contract state key between two blocks
+enum StateChange<'a> {
+    Added {
+        key: &'a [u8],
+        value: &'a [u8],
+    },
+    Removed {
+        key: &'a [u8],
+        value: &'a [u8],
+    },
+    Changed {
+        key: &'a [u8],
+        old_value: &'a [u8],
+        new_value: &'a [u8],
+    },
+}
+
+/// Compares two contract state snapshots, the changes are sorted by key
+fn diff_contract_state<'a>(
+    old_state: &'a std::collections::BTreeMap<Vec<u8>, Vec<u8>>,
+    new_state: &'a std::collections::BTreeMap<Vec<u8>, Vec<u8>>,
+) -> Vec<StateChange<'a>> {
+    let keys: std::collections::BTreeSet<&Vec<u8>> =
+        old_state.keys().chain(new_state.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| match (old_state.get(key), new_state.get(key)) {
+            (None, Some(value)) => Some(StateChange::Added { key, value }),
+            (Some(value), None) => Some(StateChange::Removed { key, value }),
+            (Some(old_value), Some(new_value)) if old_value != new_value => {
+                Some(StateChange::Changed {
+                    key,
+                    old_value,
+                    new_value,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(entries: &[(&str, &str)]) -> std::collections::BTreeMap<Vec<u8>, Vec<u8>> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.as_bytes().to_vec(), value.as_bytes().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn diff_contract_state_sorted_by_key() {
+        let old_state = state(&[("a", "1"), ("b", "2"), ("c", "3")]);
+        let new_state = state(&[("b", "2"), ("c", "4"), ("d", "5")]);
+        let changes: Vec<String> = diff_contract_state(&old_state, &new_state)
+            .into_iter()
+            .map(|change| match change {
+                StateChange::Added { key, value } => format!("+{:?}={:?}", key, value),
+                StateChange::Removed { key, value } => format!("-{:?}={:?}", key, value),
+                StateChange::Changed {
+                    key,
+                    old_value,
+                    new_value,
+                } => format!("~{:?}={:?}->{:?}", key, old_value, new_value),
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                format!("-{:?}={:?}", b"a", b"1"),
+                format!("~{:?}={:?}->{:?}", b"c", b"3", b"4"),
+                format!("+{:?}={:?}", b"d", b"5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_contract_state_unchanged() {
+        let old_state = state(&[("a", "1")]);
+        assert!(diff_contract_state(&old_state, &old_state.clone()).is_empty());
+    }
+}
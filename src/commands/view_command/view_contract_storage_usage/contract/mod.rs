@@ -0,0 +1,211 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(context = super::operation_mode::online_mode::select_server::ViewContractStorageUsageCommandNetworkContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct Contract {
+    pub contract_account_id: crate::types::account_id::AccountId,
+    ///Group the keys by their first bytes (default: 1)
+    #[interactive_clap(long)]
+    prefix_length: Option<u64>,
+    ///Group the keys by the part up to and including the separator (utf8:<text>, hex:<hex> or base64:<base64>)
+    #[interactive_clap(long)]
+    separator: Option<crate::types::store_key::StoreKey>,
+    ///Decode the prefixes as utf8, hex, base64, json or borsh:<schema> (default: utf8)
+    #[interactive_clap(long)]
+    key_format: Option<crate::types::state_format::StateFormat>,
+}
+
+impl Contract {
+    pub fn from_cli(
+        optional_clap_variant: Option<<Contract as interactive_clap::ToCli>::CliVariant>,
+        context: super::operation_mode::online_mode::select_server::ViewContractStorageUsageCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<Self> {
+        let connection_config = context.connection_config.clone();
+        let contract_account_id = match optional_clap_variant
+            .clone()
+            .and_then(|clap_variant| clap_variant.contract_account_id)
+        {
+            Some(contract_account_id) => match crate::common::get_account_state(
+                &connection_config,
+                contract_account_id.clone().into(),
+            )? {
+                Some(_) => contract_account_id,
+                None => {
+                    println!("Contract <{}> doesn't exist", contract_account_id);
+                    Self::input_contract_account_id(&context)?
+                }
+            },
+            None => Self::input_contract_account_id(&context)?,
+        };
+        let (prefix_length, separator, key_format) = match optional_clap_variant {
+            Some(clap_variant) => (
+                clap_variant.prefix_length,
+                clap_variant.separator,
+                clap_variant.key_format,
+            ),
+            None => {
+                let (prefix_length, separator) = Self::input_key_grouping(&context)?;
+                (prefix_length, separator, None)
+            }
+        };
+        if prefix_length.is_some() && separator.is_some() {
+            return Err(color_eyre::Report::msg(format!(
+                "Group the keys either by a prefix length or by a separator, not both"
+            )));
+        }
+        Ok(Self {
+            contract_account_id,
+            prefix_length,
+            separator,
+            key_format,
+        })
+    }
+}
+
+impl Contract {
+    pub fn input_contract_account_id(
+        context: &super::operation_mode::online_mode::select_server::ViewContractStorageUsageCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<crate::types::account_id::AccountId> {
+        let connection_config = context.connection_config.clone();
+        loop {
+            let account_id: crate::types::account_id::AccountId = Input::new()
+                .with_prompt("What contract storage usage do you want to analyze?")
+                .interact_text()?;
+            if let Some(_) =
+                crate::common::get_account_state(&connection_config, account_id.clone().into())?
+            {
+                break Ok(account_id);
+            } else {
+                println!("Account <{}> doesn't exist", account_id.to_string());
+            };
+        }
+    }
+
+    fn input_key_grouping(
+        _context: &super::operation_mode::online_mode::select_server::ViewContractStorageUsageCommandNetworkContext,
+    ) -> color_eyre::eyre::Result<(Option<u64>, Option<crate::types::store_key::StoreKey>)> {
+        let choose_input = vec![
+            "Group the keys by prefix length",
+            "Group the keys by separator",
+        ];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to group the contract state keys?")
+            .items(&choose_input)
+            .default(0)
+            .interact()?;
+        match select_choose_input {
+            0 => {
+                let prefix_length: u64 = Input::new()
+                    .with_prompt("Enter the prefix length in bytes")
+                    .with_initial_text("1")
+                    .interact_text()?;
+                Ok((Some(prefix_length), None))
+            }
+            1 => {
+                let separator: crate::types::store_key::StoreKey = Input::new()
+                    .with_prompt("Enter the separator (utf8:<text>, hex:<hex> or base64:<base64>)")
+                    .interact_text()?;
+                Ok((None, Some(separator)))
+            }
+            _ => unreachable!("Error"),
+        }
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let key_grouping = match self.separator {
+            Some(separator) if !separator.0.is_empty() => {
+                super::KeyGrouping::Separator(separator.0)
+            }
+            Some(_) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "The separator must not be empty"
+                )))
+            }
+            None => super::KeyGrouping::PrefixLength(self.prefix_length.unwrap_or(1) as usize),
+        };
+        let key_format = self
+            .key_format
+            .unwrap_or(crate::types::state_format::StateFormat::Utf8);
+        let contract_state = crate::common::fetch_contract_state(
+            &network_connection_config,
+            self.contract_account_id.clone().into(),
+            vec![],
+            near_primitives::types::Finality::Final.into(),
+        )
+        .await?;
+        let runtime_config = crate::common::get_runtime_config(&network_connection_config).await?;
+        let storage_amount_per_byte = runtime_config.storage_amount_per_byte;
+        let num_extra_bytes_record = runtime_config
+            .transaction_costs
+            .storage_usage_config
+            .num_extra_bytes_record;
+        let account_view = near_jsonrpc_client::JsonRpcClient::connect(
+            network_connection_config.rpc_url().as_str(),
+        )
+        .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccount {
+                account_id: self.contract_account_id.clone().into(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch query for view account: {:?}", err))
+        })?;
+        let account_view =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
+                account_view.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+
+        let usage =
+            super::storage_usage_by_prefix(&contract_state, &key_grouping, num_extra_bytes_record);
+        println!(
+            "\nStorage usage of <{}> contract state by key prefix:",
+            self.contract_account_id
+        );
+        println!(
+            "{:<40} {:>10} {:>12} {:>12}  {}",
+            "Prefix", "Entries", "Key bytes", "Value bytes", "Storage stake"
+        );
+        let mut total_storage_bytes = 0;
+        for (prefix, prefix_usage) in &usage {
+            total_storage_bytes += prefix_usage.storage_bytes(num_extra_bytes_record);
+            println!(
+                "{:<40} {:>10} {:>12} {:>12}  {}",
+                key_format.decode(prefix),
+                prefix_usage.entries,
+                prefix_usage.key_bytes,
+                prefix_usage.value_bytes,
+                crate::common::NearBalance::from_yoctonear(
+                    u128::from(prefix_usage.storage_bytes(num_extra_bytes_record))
+                        * storage_amount_per_byte
+                )
+            );
+        }
+        println!(
+            "\nThe contract state takes {} bytes ({} entries, including {} extra bytes per entry) and locks {}",
+            total_storage_bytes,
+            contract_state.len(),
+            num_extra_bytes_record,
+            crate::common::NearBalance::from_yoctonear(
+                u128::from(total_storage_bytes) * storage_amount_per_byte
+            )
+        );
+        println!(
+            "The account uses {} bytes in total (contract code, access keys and the account itself included) and locks {}",
+            account_view.storage_usage,
+            crate::common::NearBalance::from_yoctonear(
+                u128::from(account_view.storage_usage) * storage_amount_per_byte
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+mod contract;
+pub mod operation_mode;
+
+/// How the contract state keys are grouped into prefixes
+#[derive(Debug, Clone)]
+enum KeyGrouping {
+    /// The first bytes of the key
+    PrefixLength(usize),
+    /// The part of the key up to and including the first separator
+    Separator(Vec<u8>),
+}
+
+impl KeyGrouping {
+    fn prefix<'a>(&self, key: &'a [u8]) -> &'a [u8] {
+        match self {
+            Self::PrefixLength(prefix_length) => &key[..key.len().min(*prefix_length)],
+            Self::Separator(separator) => key
+                .windows(separator.len())
+                .position(|window| window == separator.as_slice())
+                .map_or(key, |position| &key[..position + separator.len()]),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PrefixStorageUsage {
+    entries: u64,
+    key_bytes: u64,
+    value_bytes: u64,
+}
+
+impl PrefixStorageUsage {
+    /// Storage bytes the entries are charged for, `num_extra_bytes_record` is charged for every
+    /// record in addition to its key and value (see the storage usage config of the runtime)
+    fn storage_bytes(&self, num_extra_bytes_record: u64) -> u64 {
+        self.key_bytes + self.value_bytes + self.entries * num_extra_bytes_record
+    }
+}
+
+/// Groups the contract state by key prefix, the largest storage consumers go first
+fn storage_usage_by_prefix(
+    contract_state: &std::collections::BTreeMap<Vec<u8>, Vec<u8>>,
+    key_grouping: &KeyGrouping,
+    num_extra_bytes_record: u64,
+) -> Vec<(Vec<u8>, PrefixStorageUsage)> {
+    let mut usage: std::collections::BTreeMap<Vec<u8>, PrefixStorageUsage> =
+        std::collections::BTreeMap::new();
+    for (key, value) in contract_state {
+        let prefix_usage = usage.entry(key_grouping.prefix(key).to_vec()).or_default();
+        prefix_usage.entries += 1;
+        prefix_usage.key_bytes += key.len() as u64;
+        prefix_usage.value_bytes += value.len() as u64;
+    }
+    let mut usage: Vec<(Vec<u8>, PrefixStorageUsage)> = usage.into_iter().collect();
+    usage.sort_by(|(_, a), (_, b)| {
+        b.storage_bytes(num_extra_bytes_record)
+            .cmp(&a.storage_bytes(num_extra_bytes_record))
+    });
+    usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_by_length() {
+        assert_eq!(KeyGrouping::PrefixLength(2).prefix(b"abc"), b"ab");
+        assert_eq!(KeyGrouping::PrefixLength(5).prefix(b"abc"), b"abc");
+    }
+
+    #[test]
+    fn prefix_by_separator() {
+        let key_grouping = KeyGrouping::Separator(b"::".to_vec());
+        assert_eq!(key_grouping.prefix(b"users::alice"), b"users::");
+        assert_eq!(key_grouping.prefix(b"a::b::c"), b"a::");
+        assert_eq!(key_grouping.prefix(b"STATE"), b"STATE");
+    }
+
+    #[test]
+    fn storage_usage_sorted_by_storage_bytes() {
+        let contract_state: std::collections::BTreeMap<Vec<u8>, Vec<u8>> = vec![
+            (b"a1".to_vec(), vec![0; 10]),
+            (b"b1".to_vec(), vec![0; 4]),
+            (b"b2".to_vec(), vec![0; 4]),
+        ]
+        .into_iter()
+        .collect();
+        let usage = storage_usage_by_prefix(&contract_state, &KeyGrouping::PrefixLength(1), 40);
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].0, b"b");
+        assert_eq!(usage[0].1.entries, 2);
+        assert_eq!(usage[0].1.key_bytes, 4);
+        assert_eq!(usage[0].1.value_bytes, 8);
+        assert_eq!(usage[0].1.storage_bytes(40), 92);
+        assert_eq!(usage[1].0, b"a");
+        assert_eq!(usage[1].1.storage_bytes(40), 52);
+        // Without the extra bytes per record the single large value comes first
+        let usage = storage_usage_by_prefix(&contract_state, &KeyGrouping::PrefixLength(1), 0);
+        assert_eq!(usage[0].0, b"a");
+    }
+}
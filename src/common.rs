@@ -1,6 +1,8 @@
 use std::convert::{TryFrom, TryInto};
 use std::io::Write;
 
+use hmac::{Mac, NewMac};
+
 use near_primitives::{
     borsh::BorshDeserialize,
     hash::CryptoHash,
@@ -393,7 +395,11 @@ pub enum ConnectionConfig {
     Testnet,
     Mainnet,
     Betanet,
-    Custom { url: url::Url },
+    /// The local sandbox node started with `near sandbox start`
+    Sandbox,
+    Custom {
+        url: url::Url,
+    },
 }
 
 impl ConnectionConfig {
@@ -403,45 +409,95 @@ impl ConnectionConfig {
         }
     }
 
+    /// The network name stored in files shared between machines: testnet, mainnet, betanet,
+    /// sandbox or the RPC URL of a custom network
+    pub fn network_name(&self) -> String {
+        match self {
+            Self::Testnet => "testnet".to_string(),
+            Self::Mainnet => "mainnet".to_string(),
+            Self::Betanet => "betanet".to_string(),
+            Self::Sandbox => "sandbox".to_string(),
+            Self::Custom { url } => url.to_string(),
+        }
+    }
+
+    pub fn from_network_name(network_name: &str) -> color_eyre::eyre::Result<Self> {
+        Ok(match network_name {
+            "testnet" => Self::Testnet,
+            "mainnet" => Self::Mainnet,
+            "betanet" => Self::Betanet,
+            "sandbox" => Self::Sandbox,
+            url => Self::Custom {
+                url: url.parse().map_err(|err| {
+                    color_eyre::Report::msg(format!("Unknown network <{}>: {}", url, err))
+                })?,
+            },
+        })
+    }
+
     pub fn rpc_url(&self) -> url::Url {
         match self {
             Self::Testnet => crate::consts::TESTNET_API_SERVER_URL.parse().unwrap(),
             Self::Mainnet => crate::consts::MAINNET_API_SERVER_URL.parse().unwrap(),
             Self::Betanet => crate::consts::BETANET_API_SERVER_URL.parse().unwrap(),
+            Self::Sandbox => crate::consts::SANDBOX_API_SERVER_URL.parse().unwrap(),
             Self::Custom { url } => url.clone(),
         }
     }
 
-    pub fn archival_rpc_url(&self) -> url::Url {
+    /// The RPC server which keeps the whole history, the sandbox node has no archival counterpart
+    pub fn archival_rpc_url(&self) -> Option<url::Url> {
         match self {
-            Self::Testnet => crate::consts::TESTNET_ARCHIVAL_API_SERVER_URL
-                .parse()
-                .unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_ARCHIVAL_API_SERVER_URL
-                .parse()
-                .unwrap(),
-            Self::Betanet => crate::consts::BETANET_ARCHIVAL_API_SERVER_URL
-                .parse()
-                .unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Testnet => Some(
+                crate::consts::TESTNET_ARCHIVAL_API_SERVER_URL
+                    .parse()
+                    .unwrap(),
+            ),
+            Self::Mainnet => Some(
+                crate::consts::MAINNET_ARCHIVAL_API_SERVER_URL
+                    .parse()
+                    .unwrap(),
+            ),
+            Self::Betanet => Some(
+                crate::consts::BETANET_ARCHIVAL_API_SERVER_URL
+                    .parse()
+                    .unwrap(),
+            ),
+            Self::Sandbox => None,
+            Self::Custom { url } => Some(url.clone()),
         }
     }
 
-    pub fn wallet_url(&self) -> url::Url {
+    /// There is no wallet for the sandbox network
+    pub fn wallet_url(&self) -> Option<url::Url> {
         match self {
-            Self::Testnet => crate::consts::TESTNET_WALLET_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_WALLET_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_WALLET_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Testnet => Some(crate::consts::TESTNET_WALLET_URL.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_WALLET_URL.parse().unwrap()),
+            Self::Betanet => Some(crate::consts::BETANET_WALLET_URL.parse().unwrap()),
+            Self::Sandbox => None,
+            Self::Custom { url } => Some(url.clone()),
         }
     }
 
-    pub fn transaction_explorer(&self) -> url::Url {
+    /// There is no transaction explorer for the sandbox network
+    pub fn transaction_explorer(&self) -> Option<url::Url> {
         match self {
-            Self::Testnet => crate::consts::TESTNET_TRANSACTION_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_TRANSACTION_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_TRANSACTION_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+            Self::Testnet => Some(crate::consts::TESTNET_TRANSACTION_URL.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_TRANSACTION_URL.parse().unwrap()),
+            Self::Betanet => Some(crate::consts::BETANET_TRANSACTION_URL.parse().unwrap()),
+            Self::Sandbox => None,
+            Self::Custom { url } => Some(url.clone()),
+        }
+    }
+
+    /// The registrar (linkdrop) contract which creates `<name>.<registrar>` accounts on this network
+    pub fn linkdrop_account_id(&self) -> Option<near_primitives::types::AccountId> {
+        match self {
+            Self::Testnet => Some(crate::consts::TESTNET_LINKDROP_ACCOUNT_ID.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_LINKDROP_ACCOUNT_ID.parse().unwrap()),
+            Self::Betanet => None,
+            Self::Sandbox => None,
+            Self::Custom { url: _ } => None,
         }
     }
 
@@ -450,6 +506,7 @@ impl ConnectionConfig {
             Self::Testnet => crate::consts::DIR_NAME_TESTNET,
             Self::Mainnet => crate::consts::DIR_NAME_MAINNET,
             Self::Betanet => crate::consts::DIR_NAME_BETANET,
+            Self::Sandbox => crate::consts::DIR_NAME_SANDBOX,
             Self::Custom { url: _ } => crate::consts::DIR_NAME_CUSTOM,
         }
     }
@@ -511,21 +568,8 @@ pub fn get_account_transfer_allowance(
                 pessimistic_transaction_fee: NearBalance::from_yoctonear(0),
             });
         };
-    let storage_amount_per_byte = actix::System::new()
-        .block_on(async {
-            near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
-                .call(
-                    near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
-                        block_reference: near_primitives::types::BlockReference::Finality(
-                            near_primitives::types::Finality::Final,
-                        ),
-                    },
-                )
-                .await
-        })
-        .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))?
-        .runtime_config
-        .storage_amount_per_byte;
+    let storage_amount_per_byte =
+        actix::System::new().block_on(get_storage_amount_per_byte(connection_config))?;
 
     Ok(AccountTransferAllowance {
         account_id,
@@ -541,6 +585,34 @@ pub fn get_account_transfer_allowance(
     })
 }
 
+/// The runtime config of the current protocol version at the final block: storage costs, fees
+/// and the limits of contracts and transactions
+pub async fn get_runtime_config(
+    connection_config: &ConnectionConfig,
+) -> color_eyre::eyre::Result<near_primitives::runtime::config::RuntimeConfig> {
+    Ok(
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(
+                near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
+                    block_reference: near_primitives::types::BlockReference::Finality(
+                        near_primitives::types::Finality::Final,
+                    ),
+                },
+            )
+            .await
+            .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))?
+            .runtime_config,
+    )
+}
+
+pub async fn get_storage_amount_per_byte(
+    connection_config: &ConnectionConfig,
+) -> color_eyre::eyre::Result<near_primitives::types::Balance> {
+    Ok(get_runtime_config(connection_config)
+        .await?
+        .storage_amount_per_byte)
+}
+
 pub fn get_account_state(
     connection_config: &ConnectionConfig,
     account_id: near_primitives::types::AccountId,
@@ -570,6 +642,404 @@ pub fn get_account_state(
     }
 }
 
+/// Calls a view method of the contract at the final block and returns the raw result.
+pub async fn call_view_function(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    method_name: &str,
+    args: Vec<u8>,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::CallFunction {
+                    account_id: contract_account_id,
+                    method_name: method_name.to_string(),
+                    args: near_primitives::types::FunctionArgs::from(args),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view method <{}>: {:?}",
+                    method_name, err
+                ))
+            })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
+        query_view_method_response.kind
+    {
+        Ok(result.result)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+/// Like `call_view_function`, but returns None if the contract has no method with this name.
+/// Any other failure, such as a panic in the method, is an error.
+pub async fn call_view_function_if_exists(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    method_name: &str,
+    args: Vec<u8>,
+) -> color_eyre::eyre::Result<Option<Vec<u8>>> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::CallFunction {
+                    account_id: contract_account_id,
+                    method_name: method_name.to_string(),
+                    args: near_primitives::types::FunctionArgs::from(args),
+                },
+            })
+            .await;
+    match query_view_method_response {
+        Ok(near_jsonrpc_primitives::types::query::RpcQueryResponse {
+            kind: near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result),
+            ..
+        }) => Ok(Some(result.result)),
+        Ok(_) => Err(color_eyre::Report::msg(format!("Error call result"))),
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::ContractExecutionError {
+                    vm_error,
+                    ..
+                },
+            ),
+        )) if vm_error.contains("MethodNotFound") => Ok(None),
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to fetch query for view method <{}>: {:?}",
+            method_name, err
+        ))),
+    }
+}
+
+/// Fetches the contract state entries whose keys start with the prefix, sorted by key.
+/// Falls back to the archival RPC server if the block is no longer available on the regular one.
+pub async fn fetch_contract_state(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    prefix: Vec<u8>,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<std::collections::BTreeMap<Vec<u8>, Vec<u8>>> {
+    let request = || near_jsonrpc_client::methods::query::RpcQueryRequest {
+        block_reference: block_reference.clone(),
+        request: near_primitives::views::QueryRequest::ViewState {
+            account_id: contract_account_id.clone(),
+            prefix: near_primitives::types::StoreKey::from(prefix.clone()),
+        },
+    };
+    let mut query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(request())
+            .await;
+    if let Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+        near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+            near_jsonrpc_primitives::types::query::RpcQueryError::UnknownBlock { .. }
+            | near_jsonrpc_primitives::types::query::RpcQueryError::GarbageCollectedBlock { .. },
+        ),
+    )) = query_view_method_response
+    {
+        if let Some(archival_rpc_url) = connection_config
+            .archival_rpc_url()
+            .filter(|archival_rpc_url| archival_rpc_url != &connection_config.rpc_url())
+        {
+            query_view_method_response =
+                near_jsonrpc_client::JsonRpcClient::connect(archival_rpc_url.as_str())
+                    .call(request())
+                    .await;
+        }
+    }
+    let query_view_method_response = match query_view_method_response {
+        Ok(response) => response,
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::TooLargeContractState {
+                    ..
+                },
+            ),
+        )) => {
+            return Err(color_eyre::Report::msg(format!(
+                "The state of <{}> is too large to be viewed at once. Narrow it down with a key prefix.",
+                contract_account_id
+            )))
+        }
+        Err(err) => {
+            return Err(color_eyre::Report::msg(format!(
+                "Failed to fetch query for view contract state: {:?}",
+                err
+            )))
+        }
+    };
+    let view_state_result =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    view_state_result
+        .values
+        .into_iter()
+        .map(|state_item| {
+            let key = base64::decode(&state_item.key).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to decode the state key: {}", err))
+            })?;
+            let value = base64::decode(&state_item.value).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to decode the state value: {}", err))
+            })?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Fetches the contract code deployed to the account at the final block.
+pub async fn get_contract_code(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let query_view_method_response =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::ViewCode {
+                    account_id: contract_account_id,
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view contract: {:?}",
+                    err
+                ))
+            })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+        query_view_method_response.kind
+    {
+        Ok(result.code)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+/// The interface of a WebAssembly module
+#[derive(Debug, Default)]
+pub struct WasmInfo {
+    pub size: usize,
+    pub exported_functions: Vec<String>,
+    /// (module, name) of the imported functions, i.e. the host functions the contract uses
+    pub imported_functions: Vec<(String, String)>,
+    /// (initial, maximum) memory size in 64KiB pages
+    pub memories: Vec<(u64, Option<u64>)>,
+}
+
+pub fn inspect_wasm(code: &[u8]) -> color_eyre::eyre::Result<WasmInfo> {
+    let mut wasm_info = WasmInfo {
+        size: code.len(),
+        ..Default::default()
+    };
+    let invalid_wasm = |err: wasmparser::BinaryReaderError| {
+        color_eyre::Report::msg(format!("Invalid WebAssembly: {}", err))
+    };
+    wasmparser::Validator::new()
+        .validate_all(code)
+        .map_err(invalid_wasm)?;
+    for payload in wasmparser::Parser::new(0).parse_all(code) {
+        match payload.map_err(invalid_wasm)? {
+            wasmparser::Payload::ExportSection(exports) => {
+                for export in exports {
+                    let export = export.map_err(invalid_wasm)?;
+                    if let wasmparser::ExternalKind::Function = export.kind {
+                        wasm_info.exported_functions.push(export.field.to_string());
+                    }
+                }
+            }
+            wasmparser::Payload::ImportSection(imports) => {
+                for import in imports {
+                    let import = import.map_err(invalid_wasm)?;
+                    match import.ty {
+                        wasmparser::ImportSectionEntryType::Function(_) => {
+                            wasm_info.imported_functions.push((
+                                import.module.to_string(),
+                                import.field.unwrap_or_default().to_string(),
+                            ))
+                        }
+                        wasmparser::ImportSectionEntryType::Memory(memory) => {
+                            wasm_info.memories.push((memory.initial, memory.maximum))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            wasmparser::Payload::MemorySection(memories) => {
+                for memory in memories {
+                    let memory = memory.map_err(invalid_wasm)?;
+                    wasm_info.memories.push((memory.initial, memory.maximum));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(wasm_info)
+}
+
+/// Checks the code against the protocol limits and the code already deployed to the account
+/// and estimates the storage stake. Returns false if the deployment should be skipped.
+/// Without a terminal to ask on, deploying the same code again is an error.
+pub async fn check_code_before_deploy(
+    connection_config: &ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    code: &[u8],
+) -> color_eyre::eyre::Result<bool> {
+    let runtime_config = get_runtime_config(connection_config).await?;
+    let max_contract_size = runtime_config.wasm_config.limit_config.max_contract_size;
+    if code.len() as u64 > max_contract_size {
+        return Err(color_eyre::Report::msg(format!(
+            "The contract takes {} bytes, the protocol allows at most {} bytes",
+            code.len(),
+            max_contract_size
+        )));
+    }
+    let account_view =
+        near_jsonrpc_client::JsonRpcClient::connect(connection_config.rpc_url().as_str())
+            .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccount {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view account: {:?}",
+                    err
+                ))
+            })?;
+    let account_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
+            account_view.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+
+    let code_hash = near_primitives::hash::hash(code);
+    if account_view.code_hash == code_hash {
+        println!(
+            "\nThe contract with the code hash <{}> is already deployed to <{}>",
+            code_hash, account_id
+        );
+        if !dialoguer::console::Term::stderr().is_term() {
+            return Err(color_eyre::Report::msg(
+                "The same contract code is already deployed, use --skip-checks true to deploy it anyway",
+            ));
+        }
+        let choose_input = vec!["Yes, I want to deploy it again", "No, skip the deployment"];
+        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to deploy the same contract code again?")
+            .items(&choose_input)
+            .default(1)
+            .interact()?;
+        if select_choose_input == 1 {
+            return Ok(false);
+        }
+    }
+
+    let deployed_code_size =
+        if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
+            0
+        } else {
+            get_contract_code(
+                connection_config,
+                account_id.clone(),
+                near_primitives::types::Finality::Final.into(),
+            )
+            .await?
+            .len()
+        };
+    let storage_amount_per_byte = runtime_config.storage_amount_per_byte;
+    if code.len() > deployed_code_size {
+        let extra_storage_stake =
+            (code.len() - deployed_code_size) as u128 * storage_amount_per_byte;
+        let available_balance = account_view
+            .amount
+            .saturating_sub(u128::from(account_view.storage_usage) * storage_amount_per_byte);
+        println!(
+            "\nThe new code takes {} more bytes and locks about {} of additional storage stake",
+            code.len() - deployed_code_size,
+            NearBalance::from_yoctonear(extra_storage_stake)
+        );
+        if extra_storage_stake > available_balance {
+            println!(
+                "The account <{}> has only {} available to cover it",
+                account_id,
+                NearBalance::from_yoctonear(available_balance)
+            );
+        }
+    } else {
+        println!(
+            "\nThe new code takes {} fewer bytes and releases about {} of storage stake",
+            deployed_code_size - code.len(),
+            NearBalance::from_yoctonear(
+                (deployed_code_size - code.len()) as u128 * storage_amount_per_byte
+            )
+        );
+    }
+    Ok(true)
+}
+
+/// Names of the functions exported by the contract deployed to the account.
+/// Returns an empty list if the code cannot be fetched or parsed.
+pub fn get_contract_method_names(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+) -> Vec<String> {
+    actix::System::new()
+        .block_on(get_contract_code(
+            connection_config,
+            contract_account_id,
+            near_primitives::types::Finality::Final.into(),
+        ))
+        .and_then(|code| inspect_wasm(&code))
+        .map(|wasm_info| wasm_info.exported_functions)
+        .unwrap_or_default()
+}
+
+/// Asks for a method name offering the functions exported by the contract as a selection list
+pub fn input_method_name(
+    connection_config: Option<&ConnectionConfig>,
+    contract_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<String> {
+    let method_names = match connection_config {
+        Some(connection_config) => {
+            get_contract_method_names(connection_config, contract_account_id)
+        }
+        None => vec![],
+    };
+    if method_names.is_empty() {
+        return Ok(dialoguer::Input::new()
+            .with_prompt("Enter a method name")
+            .interact_text()?);
+    }
+    let mut items = method_names.clone();
+    items.push("Enter a method name manually".to_string());
+    let select_method_name = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Choose a method")
+        .items(&items)
+        .default(0)
+        .interact()?;
+    match method_names.get(select_method_name) {
+        Some(method_name) => Ok(method_name.clone()),
+        None => Ok(dialoguer::Input::new()
+            .with_prompt("Enter a method name")
+            .interact_text()?),
+    }
+}
+
 /// Returns true if the account ID length is 64 characters and it's a hex representation. This is used to check the implicit account.
 pub fn is_64_len_hex(account_id: impl AsRef<str>) -> bool {
     let account_id = account_id.as_ref();
@@ -584,63 +1054,273 @@ pub fn is_64_len_hex(account_id: impl AsRef<str>) -> bool {
 pub struct KeyPairProperties {
     pub seed_phrase_hd_path: slip10::BIP32Path,
     pub master_seed_phrase: String,
-    pub implicit_account_id: near_primitives::types::AccountId,
+    /// Implicit accounts exist only for ED25519 keys
+    pub implicit_account_id: Option<near_primitives::types::AccountId>,
     pub public_key_str: String,
     pub secret_keypair_str: String,
 }
 
-pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
-    let generate_keypair: crate::commands::utils_command::generate_keypair_subcommand::CliGenerateKeypair =
-        crate::commands::utils_command::generate_keypair_subcommand::CliGenerateKeypair::default();
-    let (master_seed_phrase, master_seed) =
-        if let Some(master_seed_phrase) = generate_keypair.master_seed_phrase.as_deref() {
-            (
-                master_seed_phrase.to_owned(),
-                bip39::Mnemonic::parse(master_seed_phrase)?.to_seed(""),
-            )
-        } else {
-            let mnemonic =
-                bip39::Mnemonic::generate(generate_keypair.new_master_seed_phrase_words_count)?;
-            let master_seed_phrase = mnemonic.word_iter().collect::<Vec<&str>>().join(" ");
-            (master_seed_phrase, mnemonic.to_seed(""))
+impl KeyPairProperties {
+    /// A key pair which is not derived from a seed phrase: its master seed phrase is empty and
+    /// it is saved to the keychain without the seed phrase and the HD path
+    pub fn from_secret_key(secret_key: &near_crypto::SecretKey) -> color_eyre::eyre::Result<Self> {
+        let public_key = secret_key.public_key();
+        let implicit_account_id = match &public_key {
+            near_crypto::PublicKey::ED25519(ed25519_public_key) => Some(
+                near_primitives::types::AccountId::try_from(hex::encode(&ed25519_public_key.0))?,
+            ),
+            near_crypto::PublicKey::SECP256K1(_) => None,
         };
+        Ok(Self {
+            seed_phrase_hd_path: KeyDerivationParams::default().seed_phrase_hd_path,
+            master_seed_phrase: String::new(),
+            implicit_account_id,
+            public_key_str: public_key.to_string(),
+            secret_keypair_str: secret_key.to_string(),
+        })
+    }
+}
 
-    let derived_private_key = slip10::derive_key_from_path(
-        &master_seed,
-        slip10::Curve::Ed25519,
-        &generate_keypair.seed_phrase_hd_path,
-    )
-    .map_err(|err| {
+/// How a key pair is derived from a BIP39 seed phrase
+#[derive(Debug, Clone)]
+pub struct KeyDerivationParams {
+    /// The seed phrase to recover the key pair from; a new one is generated when it is missing
+    pub master_seed_phrase: Option<String>,
+    pub new_master_seed_phrase_words_count: usize,
+    pub seed_phrase_hd_path: slip10::BIP32Path,
+    /// The optional BIP39 passphrase; it is never stored, so it is needed to recover the key pair
+    pub passphrase: String,
+    pub key_type: near_crypto::KeyType,
+}
+
+impl Default for KeyDerivationParams {
+    fn default() -> Self {
+        Self {
+            master_seed_phrase: None,
+            new_master_seed_phrase_words_count: 12,
+            seed_phrase_hd_path: "m/44'/397'/0'".parse().unwrap(),
+            passphrase: String::new(),
+            key_type: near_crypto::KeyType::ED25519,
+        }
+    }
+}
+
+impl KeyDerivationParams {
+    /// The seed phrase options of the commands that generate a key pair; the missing ones keep
+    /// their defaults
+    pub fn from_seed_phrase_options(
+        master_seed_phrase: Option<String>,
+        seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
+        passphrase: Option<String>,
+    ) -> Self {
+        let default = Self::default();
+        Self {
+            master_seed_phrase,
+            seed_phrase_hd_path: seed_phrase_hd_path
+                .map(Into::into)
+                .unwrap_or(default.seed_phrase_hd_path),
+            passphrase: passphrase.unwrap_or_default(),
+            ..default
+        }
+    }
+}
+
+fn new_master_seed_phrase(words_count: usize) -> color_eyre::eyre::Result<String> {
+    let mnemonic = bip39::Mnemonic::generate(words_count)?;
+    Ok(mnemonic.word_iter().collect::<Vec<&str>>().join(" "))
+}
+
+/// BIP32 derivation of a SECP256K1 secret key; slip10 only implements the ED25519 curve
+fn derive_secp256k1_secret_key(
+    master_seed: &[u8],
+    seed_phrase_hd_path: &slip10::BIP32Path,
+) -> color_eyre::eyre::Result<[u8; 32]> {
+    let secp = secp256k1::Secp256k1::new();
+    let hmac_sha512 = |key: &[u8], data: &[&[u8]]| {
+        let mut mac =
+            hmac::Hmac::<sha2::Sha512>::new_varkey(key).expect("HMAC accepts keys of any length");
+        for data in data {
+            mac.update(data);
+        }
+        mac.finalize().into_bytes()
+    };
+    let invalid_key = |err: secp256k1::Error| {
         color_eyre::Report::msg(format!(
-            "Failed to derive a key from the master key: {}",
-            err
+            "The HD path {} does not lead to a valid SECP256K1 key: {:?}",
+            seed_phrase_hd_path, err
         ))
-    })?;
-
-    let secret_keypair = {
-        let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key)?;
-        let public = ed25519_dalek::PublicKey::from(&secret);
-        ed25519_dalek::Keypair { secret, public }
     };
+    let master_key = hmac_sha512(b"Bitcoin seed", &[master_seed]);
+    let mut secret_key =
+        secp256k1::key::SecretKey::from_slice(&secp, &master_key[..32]).map_err(invalid_key)?;
+    let mut chain_code = master_key[32..].to_vec();
+    let hd_path = seed_phrase_hd_path.to_string();
+    for component in hd_path.split('/').skip(1) {
+        let (index, hardened) = match component.strip_suffix('\'') {
+            Some(index) => (index, true),
+            None => (component, false),
+        };
+        let index: u32 = index.parse().map_err(|_| {
+            color_eyre::Report::msg(format!("Invalid HD path component <{}>", component))
+        })?;
+        let child_key = if hardened {
+            hmac_sha512(
+                &chain_code,
+                &[&[0], &secret_key[..], &(index | 0x8000_0000).to_be_bytes()],
+            )
+        } else {
+            let public_key = secp256k1::key::PublicKey::from_secret_key(&secp, &secret_key)
+                .map_err(invalid_key)?;
+            hmac_sha512(
+                &chain_code,
+                &[&public_key.serialize_vec(&secp, true), &index.to_be_bytes()],
+            )
+        };
+        let mut child_secret_key =
+            secp256k1::key::SecretKey::from_slice(&secp, &child_key[..32]).map_err(invalid_key)?;
+        child_secret_key
+            .add_assign(&secp, &secret_key)
+            .map_err(invalid_key)?;
+        secret_key = child_secret_key;
+        chain_code = child_key[32..].to_vec();
+    }
+    let mut key = [0; 32];
+    key.copy_from_slice(&secret_key[..]);
+    Ok(key)
+}
 
-    let implicit_account_id =
-        near_primitives::types::AccountId::try_from(hex::encode(&secret_keypair.public))?;
-    let public_key_str = format!(
-        "ed25519:{}",
-        bs58::encode(&secret_keypair.public).into_string()
-    );
-    let secret_keypair_str = format!(
-        "ed25519:{}",
-        bs58::encode(secret_keypair.to_bytes()).into_string()
-    );
-    let key_pair_properties: KeyPairProperties = KeyPairProperties {
-        seed_phrase_hd_path: generate_keypair.seed_phrase_hd_path,
+/// ED25519 keys are derived with SLIP-10 and SECP256K1 keys with BIP32, so both can be
+/// recovered from the seed phrase and HD path with the usual wallet tooling
+pub async fn generate_keypair(
+    params: &KeyDerivationParams,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let master_seed_phrase = match &params.master_seed_phrase {
+        Some(master_seed_phrase) => master_seed_phrase.to_owned(),
+        None => new_master_seed_phrase(params.new_master_seed_phrase_words_count)?,
+    };
+    let master_seed = bip39::Mnemonic::parse(&master_seed_phrase)?.to_seed(&params.passphrase);
+
+    let secret_key = match params.key_type {
+        near_crypto::KeyType::ED25519 => {
+            let derived_private_key = slip10::derive_key_from_path(
+                &master_seed,
+                slip10::Curve::Ed25519,
+                &params.seed_phrase_hd_path,
+            )
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to derive a key from the master key: {}",
+                    err
+                ))
+            })?;
+            let secret_keypair = {
+                let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key)?;
+                let public = ed25519_dalek::PublicKey::from(&secret);
+                ed25519_dalek::Keypair { secret, public }
+            };
+            format!(
+                "ed25519:{}",
+                bs58::encode(secret_keypair.to_bytes()).into_string()
+            )
+            .parse::<near_crypto::SecretKey>()?
+        }
+        near_crypto::KeyType::SECP256K1 => {
+            let derived_private_key =
+                derive_secp256k1_secret_key(&master_seed, &params.seed_phrase_hd_path)?;
+            format!(
+                "secp256k1:{}",
+                bs58::encode(&derived_private_key).into_string()
+            )
+            .parse::<near_crypto::SecretKey>()?
+        }
+    };
+    Ok(KeyPairProperties {
+        seed_phrase_hd_path: params.seed_phrase_hd_path.clone(),
         master_seed_phrase,
-        implicit_account_id,
-        public_key_str,
-        secret_keypair_str,
+        ..KeyPairProperties::from_secret_key(&secret_key)?
+    })
+}
+
+/// Derives the key pairs of several account indices from one seed phrase; the account index
+/// is the last component of the HD path (m/44'/397'/0' is the account index 0)
+pub async fn generate_keypairs(
+    params: &KeyDerivationParams,
+    account_indices: std::ops::RangeInclusive<u32>,
+) -> color_eyre::eyre::Result<Vec<KeyPairProperties>> {
+    let master_seed_phrase = match &params.master_seed_phrase {
+        Some(master_seed_phrase) => master_seed_phrase.to_owned(),
+        None => new_master_seed_phrase(params.new_master_seed_phrase_words_count)?,
+    };
+    let hd_path = params.seed_phrase_hd_path.to_string();
+    let parent_hd_path = match hd_path.rsplit_once('/') {
+        Some((parent_hd_path, _)) => parent_hd_path,
+        None => &hd_path,
     };
-    Ok(key_pair_properties)
+    let mut key_pairs = vec![];
+    for account_index in account_indices {
+        let seed_phrase_hd_path: slip10::BIP32Path =
+            format!("{}/{}'", parent_hd_path, account_index)
+                .parse()
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to build the HD path for the account index {}: {:?}",
+                        account_index, err
+                    ))
+                })?;
+        key_pairs.push(
+            generate_keypair(&KeyDerivationParams {
+                master_seed_phrase: Some(master_seed_phrase.clone()),
+                seed_phrase_hd_path,
+                ..params.clone()
+            })
+            .await?,
+        );
+    }
+    Ok(key_pairs)
+}
+
+/// ED25519 signatures are made for the message itself. SECP256K1 signatures are made for a
+/// 32-byte digest: the message is that digest when `message_is_digest` is set and is hashed
+/// with sha256 otherwise
+pub fn verify_message_signature(
+    message: &[u8],
+    message_is_digest: bool,
+    signature: &near_crypto::Signature,
+    public_key: &near_crypto::PublicKey,
+) -> bool {
+    match signature {
+        // near-crypto panics on a recovery id other than 0..=3 instead of rejecting the signature
+        near_crypto::Signature::SECP256K1(secp256k1_signature)
+            if <[u8; 65]>::from(secp256k1_signature.clone())[64] > 3 =>
+        {
+            false
+        }
+        near_crypto::Signature::SECP256K1(_) if message_is_digest => {
+            message.len() == 32 && signature.verify(message, public_key)
+        }
+        near_crypto::Signature::SECP256K1(_) => {
+            signature.verify(near_primitives::hash::hash(message).as_ref(), public_key)
+        }
+        _ => signature.verify(message, public_key),
+    }
+}
+
+pub fn verify_transaction_signature(
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> crate::CliResult {
+    let (transaction_hash, _) = signed_transaction.transaction.get_hash_and_size();
+    if !verify_message_signature(
+        transaction_hash.as_ref(),
+        true,
+        &signed_transaction.signature,
+        &signed_transaction.transaction.public_key,
+    ) {
+        return Err(color_eyre::Report::msg(format!(
+            "The signature does not match the public key <{}> and the transaction hash {}",
+            signed_transaction.transaction.public_key, transaction_hash
+        )));
+    }
+    Ok(())
 }
 
 pub fn print_transaction(transaction: near_primitives::transaction::Transaction) {
@@ -1129,31 +1809,153 @@ pub fn print_transaction_status(
             print_value_successful_transaction(transaction_info.clone())
         }
     };
-    let transaction_explorer: url::Url = match network_connection_config {
+    let transaction_explorer: Option<url::Url> = match network_connection_config {
         Some(connection_config) => connection_config.transaction_explorer(),
         None => unreachable!("Error"),
     };
-    println!("Transaction ID: {id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
-        id=transaction_info.transaction_outcome.id,
-        path=transaction_explorer
-    );
+    match transaction_explorer {
+        Some(transaction_explorer) => println!("Transaction ID: {id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
+            id=transaction_info.transaction_outcome.id,
+            path=transaction_explorer
+        ),
+        None => println!("Transaction ID: {}\n", transaction_info.transaction_outcome.id),
+    }
+}
+
+/// An access key stored in the keychain
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct KeychainAccessKey {
+    pub public_key: near_crypto::PublicKey,
+    pub private_key: near_crypto::SecretKey,
+    /// Only function-call access keys are annotated with their permission
+    #[serde(default)]
+    pub permission: Option<near_primitives::views::AccessKeyPermissionView>,
 }
 
+impl KeychainAccessKey {
+    pub fn is_full_access(&self) -> bool {
+        match &self.permission {
+            None | Some(near_primitives::views::AccessKeyPermissionView::FullAccess) => true,
+            Some(near_primitives::views::AccessKeyPermissionView::FunctionCall { .. }) => false,
+        }
+    }
+}
+
+/// Path of the file written by `save_access_key_to_keychain` for the access key
+pub fn keychain_access_key_path(
+    connection_config: &ConnectionConfig,
+    account_id: &str,
+    public_key: &near_crypto::PublicKey,
+) -> std::path::PathBuf {
+    let mut path =
+        std::path::PathBuf::from(dirs::home_dir().expect("Impossible to get your home dir!"));
+    path.push(connection_config.dir_name());
+    path.push(account_id);
+    path.push(format!("{}.json", public_key.to_string().replace(":", "_")));
+    path
+}
+
+/// Path of the `<account_id>.json` file which holds a full access key of the account
+pub fn keychain_account_path(
+    connection_config: &ConnectionConfig,
+    account_id: &str,
+) -> std::path::PathBuf {
+    let mut path =
+        std::path::PathBuf::from(dirs::home_dir().expect("Impossible to get your home dir!"));
+    path.push(connection_config.dir_name());
+    path.push(format!("{}.json", account_id));
+    path
+}
+
+pub fn read_keychain_access_key(
+    path: &std::path::Path,
+) -> color_eyre::eyre::Result<KeychainAccessKey> {
+    let data = std::fs::read_to_string(path).map_err(|err| {
+        color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
+    })?;
+    serde_json::from_str(&data)
+        .map_err(|err| color_eyre::Report::msg(format!("Error reading data: {}", err)))
+}
+
+/// Finds the access key in the keychain: in `<account_id>/<public_key>.json`, then in `<account_id>.json`
+pub fn find_keychain_access_key(
+    connection_config: &ConnectionConfig,
+    account_id: &str,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<KeychainAccessKey> {
+    [
+        keychain_access_key_path(connection_config, account_id, public_key),
+        keychain_account_path(connection_config, account_id),
+    ]
+    .iter()
+    .filter_map(|path| read_keychain_access_key(path).ok())
+    .find(|access_key| &access_key.public_key == public_key)
+    .ok_or_else(|| {
+        color_eyre::Report::msg(format!(
+            "The access key <{}> of <{}> is not found in the keychain",
+            public_key, account_id
+        ))
+    })
+}
+
+/// All the access keys of the account in the keychain, the one of `<account_id>.json` goes first
+pub fn keychain_access_keys(
+    connection_config: &ConnectionConfig,
+    account_id: &str,
+) -> Vec<KeychainAccessKey> {
+    let mut key_files = vec![keychain_account_path(connection_config, account_id)];
+    let mut account_dir_path =
+        std::path::PathBuf::from(dirs::home_dir().expect("Impossible to get your home dir!"));
+    account_dir_path.push(connection_config.dir_name());
+    account_dir_path.push(account_id);
+    if let Ok(dir) = account_dir_path.read_dir() {
+        key_files.extend(dir.flatten().map(|entry| entry.path()).filter(|path| {
+            path.extension()
+                .map_or(false, |extension| extension == "json")
+        }));
+    }
+    let mut access_keys: Vec<KeychainAccessKey> = vec![];
+    for access_key in key_files
+        .iter()
+        .filter_map(|path| read_keychain_access_key(path).ok())
+    {
+        if !access_keys
+            .iter()
+            .any(|known_access_key| known_access_key.public_key == access_key.public_key)
+        {
+            access_keys.push(access_key);
+        }
+    }
+    access_keys
+}
+
+/// Saves the access key to the keychain. Function-call access keys are annotated with their
+/// permission; the `<account_id>.json` file is reserved for full access keys, so it is written
+/// for them only.
 pub async fn save_access_key_to_keychain(
     network_connection_config: Option<crate::common::ConnectionConfig>,
     key_pair_properties: crate::common::KeyPairProperties,
     account_id: &str,
+    permission: near_primitives::views::AccessKeyPermissionView,
 ) -> crate::CliResult {
-    let buf = format!(
-        "{}",
-        serde_json::json!({
-            "master_seed_phrase": key_pair_properties.master_seed_phrase,
-            "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
-            "account_id": account_id,
-            "public_key": key_pair_properties.public_key_str,
-            "private_key": key_pair_properties.secret_keypair_str,
-        })
+    let is_full_access = matches!(
+        permission,
+        near_primitives::views::AccessKeyPermissionView::FullAccess
     );
+    let mut key_data = serde_json::json!({
+        "account_id": account_id,
+        "public_key": key_pair_properties.public_key_str,
+        "private_key": key_pair_properties.secret_keypair_str,
+    });
+    if !key_pair_properties.master_seed_phrase.is_empty() {
+        key_data["master_seed_phrase"] = serde_json::json!(key_pair_properties.master_seed_phrase);
+        key_data["seed_phrase_hd_path"] =
+            serde_json::json!(key_pair_properties.seed_phrase_hd_path.to_string());
+    }
+    if !is_full_access {
+        key_data["permission"] = serde_json::json!(permission);
+    }
+    let buf = format!("{}", key_data);
     let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
     let dir_name = match &network_connection_config {
         Some(connection_config) => connection_config.dir_name(),
@@ -1174,9 +1976,17 @@ pub async fn save_access_key_to_keychain(
         .write(buf.as_bytes())
         .map_err(|err| color_eyre::Report::msg(format!("Failed to write to file: {:?}", err)))?;
     println!(
-        "The data for the access key is saved in a file {}",
+        "The data for the {} is saved in a file {}",
+        if is_full_access {
+            "access key"
+        } else {
+            "function-call access key"
+        },
         &path_with_key_name.display()
     );
+    if !is_full_access {
+        return Ok(());
+    }
 
     let file_with_account_name: std::path::PathBuf = format!("{}.json", account_id).into();
     let mut path_with_account_name = std::path::PathBuf::from(&home_dir);
@@ -1266,7 +2076,8 @@ pub async fn display_account_info(
     conf: &ConnectionConfig,
     block_ref: BlockReference,
 ) -> crate::CliResult {
-    let resp = near_jsonrpc_client::JsonRpcClient::connect(&conf.archival_rpc_url().as_str())
+    let archival_rpc_url = conf.archival_rpc_url().unwrap_or_else(|| conf.rpc_url());
+    let resp = near_jsonrpc_client::JsonRpcClient::connect(archival_rpc_url.as_str())
         .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
             block_reference: block_ref,
             request: QueryRequest::ViewAccount {
@@ -1312,7 +2123,8 @@ pub async fn display_access_key_list(
     conf: &ConnectionConfig,
     block_ref: BlockReference,
 ) -> crate::CliResult {
-    let resp = near_jsonrpc_client::JsonRpcClient::connect(&conf.archival_rpc_url().as_str())
+    let archival_rpc_url = conf.archival_rpc_url().unwrap_or_else(|| conf.rpc_url());
+    let resp = near_jsonrpc_client::JsonRpcClient::connect(archival_rpc_url.as_str())
         .call(near_jsonrpc_client::methods::query::RpcQueryRequest {
             block_reference: block_ref,
             request: QueryRequest::ViewAccessKeyList { account_id },
@@ -1719,4 +2531,36 @@ mod tests {
             Err("Near Gas: invalid digit found in string".to_string())
         );
     }
+
+    #[test]
+    fn derive_secp256k1_secret_key_bip32_test_vector_1() {
+        let master_seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        for (seed_phrase_hd_path, secret_key) in [
+            (
+                "m/0'",
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            ),
+            (
+                "m/0'/1",
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            ),
+            (
+                "m/0'/1/2'",
+                "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+            ),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                hex::encode(
+                    derive_secp256k1_secret_key(
+                        &master_seed,
+                        &slip10::BIP32Path::from_str(seed_phrase_hd_path).unwrap()
+                    )
+                    .unwrap()
+                ),
+                *secret_key
+            );
+        }
+    }
 }
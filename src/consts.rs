@@ -5,6 +5,8 @@ pub const MAINNET_ARCHIVAL_API_SERVER_URL: &str = "https://archival-rpc.mainnet.
 pub const BETANET_API_SERVER_URL: &str = "https://rpc.betanet.near.org";
 // NOTE: There is no dedicated archival RPC server for betanet by design
 pub const BETANET_ARCHIVAL_API_SERVER_URL: &str = "https://rpc.betanet.near.org";
+// The local sandbox node keeps the whole history, so it is its own archival node
+pub const SANDBOX_API_SERVER_URL: &str = "http://127.0.0.1:3030";
 
 pub const TESTNET_WALLET_URL: &str = "https://wallet.testnet.near.org";
 pub const MAINNET_WALLET_URL: &str = "https://wallet.mainnet.near.org";
@@ -14,8 +16,13 @@ pub const TESTNET_TRANSACTION_URL: &str = "https://explorer.testnet.near.org/tra
 pub const MAINNET_TRANSACTION_URL: &str = "https://explorer.mainnet.near.org/transactions/";
 pub const BETANET_TRANSACTION_URL: &str = "https://explorer.betanet.near.org/transactions/";
 
+// Accounts of the registrar (linkdrop) contracts used to create top-level accounts
+pub const TESTNET_LINKDROP_ACCOUNT_ID: &str = "testnet";
+pub const MAINNET_LINKDROP_ACCOUNT_ID: &str = "near";
+
 pub const DIR_NAME_KEY_CHAIN: &str = ".near-credentials/default/";
 pub const DIR_NAME_TESTNET: &str = ".near-credentials/testnet/";
 pub const DIR_NAME_MAINNET: &str = ".near-credentials/mainnet/";
 pub const DIR_NAME_BETANET: &str = ".near-credentials/betanet/";
+pub const DIR_NAME_SANDBOX: &str = ".near-credentials/sandbox/";
 pub const DIR_NAME_CUSTOM: &str = ".near-credentials/default/";
@@ -6,6 +6,7 @@ use common::{try_external_subcommand_execution, CliResult};
 mod commands;
 mod common;
 mod consts;
+mod qr_code;
 mod types;
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
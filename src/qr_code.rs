@@ -0,0 +1,169 @@
+//! Transport of base64-encoded transactions between machines through terminal QR codes.
+//!
+//! A payload that does not fit into a single comfortably scannable QR code is split into
+//! numbered frames of the form `near:<part>/<total>:<chunk>`, which can be scanned in any order.
+
+use qrcode::render::unicode;
+
+const FRAME_PREFIX: &str = "near:";
+/// Keeps every frame small enough to be rendered in a regular terminal window and scanned reliably
+const MAX_FRAME_PAYLOAD_LENGTH: usize = 400;
+
+pub fn split_into_frames(payload: &str) -> Vec<String> {
+    // base64 payloads are ASCII, so splitting by bytes never breaks a character
+    let chunks = payload
+        .as_bytes()
+        .chunks(MAX_FRAME_PAYLOAD_LENGTH)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>();
+    let total = chunks.len().max(1);
+    if total == 1 {
+        return vec![payload.to_string()];
+    }
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, chunk)| format!("{}{}/{}:{}", FRAME_PREFIX, index + 1, total, chunk))
+        .collect()
+}
+
+/// Collects scanned frames (in any order) until the whole payload is available
+#[derive(Debug, Default)]
+pub struct FrameCollector {
+    total: Option<usize>,
+    parts: std::collections::BTreeMap<usize, String>,
+}
+
+impl FrameCollector {
+    pub fn add(&mut self, frame: &str) -> color_eyre::eyre::Result<()> {
+        let frame = frame.trim();
+        let (part, total, chunk) = match frame.strip_prefix(FRAME_PREFIX) {
+            Some(framed) => parse_frame(framed)?,
+            // A payload small enough for a single QR code is not framed
+            None => (1, 1, frame.to_string()),
+        };
+        match self.total {
+            Some(expected_total) if expected_total != total => {
+                return Err(color_eyre::Report::msg(format!(
+                    "The part belongs to a different payload: it is one of {} parts, expected one of {}",
+                    total, expected_total
+                )))
+            }
+            _ => self.total = Some(total),
+        }
+        if let Some(existing_chunk) = self.parts.get(&part) {
+            if existing_chunk != &chunk {
+                return Err(color_eyre::Report::msg(format!(
+                    "Part {} was already scanned with different content",
+                    part
+                )));
+            }
+        }
+        self.parts.insert(part, chunk);
+        Ok(())
+    }
+
+    pub fn missing_parts(&self) -> Vec<usize> {
+        match self.total {
+            Some(total) => (1..=total)
+                .filter(|part| !self.parts.contains_key(part))
+                .collect(),
+            None => vec![1],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing_parts().is_empty()
+    }
+
+    pub fn into_payload(self) -> color_eyre::eyre::Result<String> {
+        let missing_parts = self.missing_parts();
+        if !missing_parts.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "Parts {:?} are missing",
+                missing_parts
+            )));
+        }
+        Ok(self.parts.into_iter().map(|(_, chunk)| chunk).collect())
+    }
+}
+
+fn parse_frame(framed: &str) -> color_eyre::eyre::Result<(usize, usize, String)> {
+    let invalid_frame = || {
+        color_eyre::Report::msg(format!(
+            "Invalid QR code part <{}{}>: expected {}<part>/<total>:<data>",
+            FRAME_PREFIX, framed, FRAME_PREFIX
+        ))
+    };
+    let (position, chunk) = framed.split_once(':').ok_or_else(invalid_frame)?;
+    let (part, total) = position.split_once('/').ok_or_else(invalid_frame)?;
+    let part: usize = part.parse().map_err(|_| invalid_frame())?;
+    let total: usize = total.parse().map_err(|_| invalid_frame())?;
+    if part == 0 || part > total {
+        return Err(invalid_frame());
+    }
+    Ok((part, total, chunk.to_string()))
+}
+
+/// Prints the payload as one or more QR codes, waiting for Enter between the frames
+pub fn print_qr_codes(payload: &str) -> crate::CliResult {
+    let frames = split_into_frames(payload);
+    let total = frames.len();
+    for (index, frame) in frames.iter().enumerate() {
+        let code =
+            qrcode::QrCode::with_error_correction_level(frame.as_bytes(), qrcode::EcLevel::L)
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to render the QR code: {}", err))
+                })?;
+        // Inverted colors, since most terminals have a dark background
+        let image = code
+            .render::<unicode::Dense1x2>()
+            .dark_color(unicode::Dense1x2::Light)
+            .light_color(unicode::Dense1x2::Dark)
+            .build();
+        if total > 1 {
+            println!("\nQR code part {} of {}:", index + 1, total);
+        }
+        println!("\n{}", image);
+        if index + 1 < total {
+            println!("Scan the QR code and press Enter to show the next part ...");
+            std::io::stdin().read_line(&mut String::new())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_are_reassembled_in_any_order() {
+        let payload = "QUJD".repeat(250);
+        let mut frames = split_into_frames(&payload);
+        assert_eq!(frames.len(), 3);
+        assert!(frames[0].starts_with("near:1/3:"));
+        frames.reverse();
+
+        let mut collector = FrameCollector::default();
+        for frame in &frames[..2] {
+            collector.add(frame).unwrap();
+        }
+        assert_eq!(collector.missing_parts(), vec![1]);
+        collector.add(&frames[2]).unwrap();
+        assert_eq!(collector.into_payload().unwrap(), payload);
+
+        let mut collector = FrameCollector::default();
+        collector.add(&split_into_frames("QUJD")[0]).unwrap();
+        assert_eq!(collector.into_payload().unwrap(), "QUJD");
+
+        let mut collector = FrameCollector::default();
+        collector.add("near:1/2:QUJD").unwrap();
+        assert!(collector.add("near:2/3:QUJD").is_err());
+        assert!(collector.add("near:3/2:QUJD").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyType(pub near_crypto::KeyType);
+
+impl Default for KeyType {
+    fn default() -> Self {
+        Self(near_crypto::KeyType::ED25519)
+    }
+}
+
+impl std::fmt::Display for KeyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::str::FromStr for KeyType {
+    type Err = near_crypto::ParseKeyTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key_type = near_crypto::KeyType::from_str(s)?;
+        Ok(Self(key_type))
+    }
+}
+
+impl From<KeyType> for near_crypto::KeyType {
+    fn from(item: KeyType) -> Self {
+        item.0
+    }
+}
+
+impl From<near_crypto::KeyType> for KeyType {
+    fn from(item: near_crypto::KeyType) -> Self {
+        Self(item)
+    }
+}
+
+impl interactive_clap::ToCli for KeyType {
+    type CliVariant = KeyType;
+}
+
+/// Asks for the type of a key pair to generate
+pub fn input_key_type() -> color_eyre::eyre::Result<KeyType> {
+    let key_types = [
+        near_crypto::KeyType::ED25519,
+        near_crypto::KeyType::SECP256K1,
+    ];
+    let selection = dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+        .with_prompt("Select the key type")
+        .items(&["ED25519", "SECP256K1"])
+        .default(0)
+        .interact()?;
+    Ok(KeyType(key_types[selection]))
+}
@@ -1,8 +1,11 @@
 pub mod account_id;
 pub mod crypto_hash;
+pub mod key_type;
 pub mod path_buf;
 pub mod public_key;
 pub mod secret_key;
 pub mod signature;
 pub mod slip10;
+pub mod state_format;
+pub mod store_key;
 pub mod vec_string;
@@ -0,0 +1,454 @@
+use std::convert::TryInto;
+
+/// How contract state keys and values are decoded for display:
+/// `utf8`, `hex`, `base64`, `json` or `borsh:<schema>`
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateFormat {
+    Utf8,
+    Hex,
+    Base64,
+    Json,
+    Borsh(BorshSchema),
+}
+
+impl Default for StateFormat {
+    fn default() -> Self {
+        Self::Base64
+    }
+}
+
+impl std::fmt::Display for StateFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Utf8 => write!(f, "utf8"),
+            Self::Hex => write!(f, "hex"),
+            Self::Base64 => write!(f, "base64"),
+            Self::Json => write!(f, "json"),
+            Self::Borsh(schema) => write!(f, "borsh:{}", schema),
+        }
+    }
+}
+
+impl std::str::FromStr for StateFormat {
+    type Err = color_eyre::eyre::ErrReport;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "utf8" => Ok(Self::Utf8),
+            "hex" => Ok(Self::Hex),
+            "base64" => Ok(Self::Base64),
+            "json" => Ok(Self::Json),
+            format => match format.strip_prefix("borsh:") {
+                Some(schema) => Ok(Self::Borsh(schema.parse()?)),
+                None => Err(color_eyre::Report::msg(format!(
+                    "Unknown format <{}>. Expected one of: utf8, hex, base64, json, borsh:<schema>",
+                    format
+                ))),
+            },
+        }
+    }
+}
+
+impl interactive_clap::ToCli for StateFormat {
+    type CliVariant = StateFormat;
+}
+
+/// Asks how the keys or the values of the contract state should be decoded; the first of the
+/// formats is the default one
+pub fn input_state_format(
+    decoded: &str,
+    formats: &[&str],
+) -> color_eyre::eyre::Result<Option<StateFormat>> {
+    let select_format = dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+        .with_prompt(format!("How do you want to decode the {}?", decoded))
+        .items(formats)
+        .default(0)
+        .interact()?;
+    match formats[select_format] {
+        "borsh" => {
+            let schema: BorshSchema = dialoguer::Input::new()
+                .with_prompt(format!(
+                    "Enter the Borsh schema of the {} (e.g. {{owner_id: string, balance: u128}})",
+                    decoded
+                ))
+                .interact_text()?;
+            Ok(Some(StateFormat::Borsh(schema)))
+        }
+        format => Ok(Some(format.parse()?)),
+    }
+}
+
+impl StateFormat {
+    /// Decodes the bytes in this format; data that cannot be decoded is shown as `base64:<data>`
+    pub fn decode(&self, data: &[u8]) -> String {
+        let decoded = match self {
+            Self::Utf8 => std::str::from_utf8(data).ok().map(String::from),
+            Self::Hex => Some(hex::encode(data)),
+            Self::Base64 => Some(base64::encode(data)),
+            Self::Json => serde_json::from_slice::<serde_json::Value>(data)
+                .ok()
+                .map(|value| value.to_string()),
+            Self::Borsh(schema) => schema.decode(data).ok().map(|value| value.to_string()),
+        };
+        decoded.unwrap_or_else(|| format!("base64:{}", base64::encode(data)))
+    }
+}
+
+/// A simple Borsh schema, e.g. `u64`, `vec<string>`, `option<u128>`, `(string, u32)`
+/// or `{owner_id: string, balance: u128}`
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorshSchema {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Bool,
+    String,
+    Vec(Box<BorshSchema>),
+    Option(Box<BorshSchema>),
+    Tuple(Vec<BorshSchema>),
+    Struct(Vec<(String, BorshSchema)>),
+}
+
+impl std::fmt::Display for BorshSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::I8 => write!(f, "i8"),
+            Self::I16 => write!(f, "i16"),
+            Self::I32 => write!(f, "i32"),
+            Self::I64 => write!(f, "i64"),
+            Self::I128 => write!(f, "i128"),
+            Self::Bool => write!(f, "bool"),
+            Self::String => write!(f, "string"),
+            Self::Vec(item) => write!(f, "vec<{}>", item),
+            Self::Option(item) => write!(f, "option<{}>", item),
+            Self::Tuple(items) => {
+                let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                write!(f, "({})", items.join(", "))
+            }
+            Self::Struct(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(name, field)| format!("{}: {}", name, field))
+                    .collect();
+                write!(f, "{{{}}}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for BorshSchema {
+    type Err = color_eyre::eyre::ErrReport;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = SchemaParser {
+            input: s.as_bytes(),
+            position: 0,
+        };
+        let schema = parser.parse_schema()?;
+        parser.skip_whitespaces();
+        if parser.position != s.len() {
+            return Err(color_eyre::Report::msg(format!(
+                "Unexpected input in the Borsh schema at position {}: <{}>",
+                parser.position, s
+            )));
+        }
+        Ok(schema)
+    }
+}
+
+impl BorshSchema {
+    /// Decodes Borsh-serialized data into JSON; all the data must be consumed
+    pub fn decode(&self, data: &[u8]) -> color_eyre::eyre::Result<serde_json::Value> {
+        let mut reader = data;
+        let value = self.read(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "{} bytes left after decoding the data with the Borsh schema <{}>",
+                reader.len(),
+                self
+            )));
+        }
+        Ok(value)
+    }
+
+    fn read(&self, reader: &mut &[u8]) -> color_eyre::eyre::Result<serde_json::Value> {
+        Ok(match self {
+            Self::U8 => read_bytes::<1>(reader)?[0].into(),
+            Self::U16 => u16::from_le_bytes(read_bytes(reader)?).into(),
+            Self::U32 => u32::from_le_bytes(read_bytes(reader)?).into(),
+            Self::U64 => u64::from_le_bytes(read_bytes(reader)?).into(),
+            Self::U128 => u128::from_le_bytes(read_bytes(reader)?).to_string().into(),
+            Self::I8 => i8::from_le_bytes(read_bytes(reader)?).into(),
+            Self::I16 => i16::from_le_bytes(read_bytes(reader)?).into(),
+            Self::I32 => i32::from_le_bytes(read_bytes(reader)?).into(),
+            Self::I64 => i64::from_le_bytes(read_bytes(reader)?).into(),
+            Self::I128 => i128::from_le_bytes(read_bytes(reader)?).to_string().into(),
+            Self::Bool => match read_bytes::<1>(reader)?[0] {
+                0 => false.into(),
+                1 => true.into(),
+                byte => {
+                    return Err(color_eyre::Report::msg(format!(
+                        "Invalid Borsh bool value: {}",
+                        byte
+                    )))
+                }
+            },
+            Self::String => {
+                let len = u32::from_le_bytes(read_bytes(reader)?) as usize;
+                if reader.len() < len {
+                    return Err(color_eyre::Report::msg("Unexpected end of the Borsh data"));
+                }
+                let (string, rest) = reader.split_at(len);
+                *reader = rest;
+                String::from_utf8(string.to_vec())
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!("Invalid Borsh string: {}", err))
+                    })?
+                    .into()
+            }
+            Self::Vec(item) => {
+                let len = u32::from_le_bytes(read_bytes(reader)?);
+                // Every item takes at least one byte except for the zero-size ones, which
+                // could make a forged length loop for a long time
+                if len as usize > reader.len() {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The Borsh vec length {} exceeds the remaining data",
+                        len
+                    )));
+                }
+                let mut items = vec![];
+                for _ in 0..len {
+                    items.push(item.read(reader)?);
+                }
+                items.into()
+            }
+            Self::Option(item) => match read_bytes::<1>(reader)?[0] {
+                0 => serde_json::Value::Null,
+                1 => item.read(reader)?,
+                byte => {
+                    return Err(color_eyre::Report::msg(format!(
+                        "Invalid Borsh option tag: {}",
+                        byte
+                    )))
+                }
+            },
+            Self::Tuple(items) => items
+                .iter()
+                .map(|item| item.read(reader))
+                .collect::<color_eyre::eyre::Result<Vec<_>>>()?
+                .into(),
+            Self::Struct(fields) => {
+                let mut object = serde_json::Map::new();
+                for (name, field) in fields {
+                    object.insert(name.clone(), field.read(reader)?);
+                }
+                object.into()
+            }
+        })
+    }
+}
+
+fn read_bytes<const N: usize>(reader: &mut &[u8]) -> color_eyre::eyre::Result<[u8; N]> {
+    if reader.len() < N {
+        return Err(color_eyre::Report::msg("Unexpected end of the Borsh data"));
+    }
+    let (bytes, rest) = reader.split_at(N);
+    *reader = rest;
+    Ok(bytes.try_into()?)
+}
+
+struct SchemaParser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SchemaParser<'a> {
+    fn skip_whitespaces(&mut self) {
+        while self.position < self.input.len() && self.input[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespaces();
+        self.input.get(self.position).copied()
+    }
+
+    fn expect(&mut self, symbol: u8) -> color_eyre::eyre::Result<()> {
+        if self.peek() == Some(symbol) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(color_eyre::Report::msg(format!(
+                "Expected <{}> in the Borsh schema at position {}",
+                symbol as char, self.position
+            )))
+        }
+    }
+
+    fn parse_identifier(&mut self) -> color_eyre::eyre::Result<String> {
+        self.skip_whitespaces();
+        let start = self.position;
+        while self.position < self.input.len()
+            && (self.input[self.position].is_ascii_alphanumeric()
+                || self.input[self.position] == b'_')
+        {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(color_eyre::Report::msg(format!(
+                "Expected a type or a field name in the Borsh schema at position {}",
+                start
+            )));
+        }
+        Ok(String::from_utf8_lossy(&self.input[start..self.position]).to_string())
+    }
+
+    /// Parses a comma separated list of items until the closing symbol
+    fn parse_list<T>(
+        &mut self,
+        closing: u8,
+        mut parse_item: impl FnMut(&mut Self) -> color_eyre::eyre::Result<T>,
+    ) -> color_eyre::eyre::Result<Vec<T>> {
+        let mut items = vec![];
+        loop {
+            if self.peek() == Some(closing) {
+                self.position += 1;
+                break Ok(items);
+            }
+            if !items.is_empty() {
+                self.expect(b',')?;
+            }
+            items.push(parse_item(self)?);
+        }
+    }
+
+    fn parse_schema(&mut self) -> color_eyre::eyre::Result<BorshSchema> {
+        match self.peek() {
+            Some(b'(') => {
+                self.position += 1;
+                Ok(BorshSchema::Tuple(
+                    self.parse_list(b')', |parser| parser.parse_schema())?,
+                ))
+            }
+            Some(b'{') => {
+                self.position += 1;
+                Ok(BorshSchema::Struct(self.parse_list(b'}', |parser| {
+                    let name = parser.parse_identifier()?;
+                    parser.expect(b':')?;
+                    Ok((name, parser.parse_schema()?))
+                })?))
+            }
+            _ => {
+                let identifier = self.parse_identifier()?;
+                Ok(match identifier.as_str() {
+                    "u8" => BorshSchema::U8,
+                    "u16" => BorshSchema::U16,
+                    "u32" => BorshSchema::U32,
+                    "u64" => BorshSchema::U64,
+                    "u128" => BorshSchema::U128,
+                    "i8" => BorshSchema::I8,
+                    "i16" => BorshSchema::I16,
+                    "i32" => BorshSchema::I32,
+                    "i64" => BorshSchema::I64,
+                    "i128" => BorshSchema::I128,
+                    "bool" => BorshSchema::Bool,
+                    "string" => BorshSchema::String,
+                    "vec" | "option" => {
+                        self.expect(b'<')?;
+                        let item = Box::new(self.parse_schema()?);
+                        self.expect(b'>')?;
+                        if identifier == "vec" {
+                            BorshSchema::Vec(item)
+                        } else {
+                            BorshSchema::Option(item)
+                        }
+                    }
+                    _ => {
+                        return Err(color_eyre::Report::msg(format!(
+                            "Unknown type <{}> in the Borsh schema",
+                            identifier
+                        )))
+                    }
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_borsh_schema() {
+        let schema: BorshSchema = "{owner_id: string, balances: vec<(u8, option<u128>)>}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            schema,
+            BorshSchema::Struct(vec![
+                ("owner_id".to_string(), BorshSchema::String),
+                (
+                    "balances".to_string(),
+                    BorshSchema::Vec(Box::new(BorshSchema::Tuple(vec![
+                        BorshSchema::U8,
+                        BorshSchema::Option(Box::new(BorshSchema::U128)),
+                    ])))
+                ),
+            ])
+        );
+        assert_eq!(
+            schema.to_string(),
+            "{owner_id: string, balances: vec<(u8, option<u128>)>}"
+        );
+        assert!("vec<u8".parse::<BorshSchema>().is_err());
+        assert!("u256".parse::<BorshSchema>().is_err());
+    }
+
+    #[test]
+    fn decode_borsh_data() {
+        let schema: BorshSchema = "(string, option<u128>, bool)".parse().unwrap();
+        let mut data = vec![3, 0, 0, 0, b'a', b'b', b'c', 1];
+        data.extend_from_slice(&10u128.to_le_bytes());
+        data.push(1);
+        assert_eq!(
+            schema.decode(&data).unwrap(),
+            serde_json::json!(["abc", "10", true])
+        );
+        data.push(0);
+        assert!(schema.decode(&data).is_err());
+    }
+
+    #[test]
+    fn reject_vec_length_beyond_data() {
+        let schema: BorshSchema = "vec<u8>".parse().unwrap();
+        assert_eq!(
+            schema.decode(&[2, 0, 0, 0, 7, 8]).unwrap(),
+            serde_json::json!([7, 8])
+        );
+        assert!(schema.decode(&[3, 0, 0, 0, 7, 8]).is_err());
+        let schema: BorshSchema = "vec<()>".parse().unwrap();
+        assert!(schema.decode(&u32::MAX.to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn decode_with_fallback() {
+        assert_eq!(StateFormat::Utf8.decode(b"STATE"), "STATE");
+        assert_eq!(StateFormat::Utf8.decode(&[0xff]), "base64:/w==");
+        assert_eq!(StateFormat::Json.decode(b"{\"a\": 1}"), "{\"a\":1}");
+        assert_eq!(StateFormat::Hex.decode(&[1, 171]), "01ab");
+    }
+}
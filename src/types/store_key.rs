@@ -0,0 +1,108 @@
+/// A raw contract state key (or key prefix) given as `utf8:<text>`, `hex:<hex>` or `base64:<base64>`.
+/// A value without an encoding tag is treated as UTF-8.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StoreKey(pub Vec<u8>);
+
+impl From<StoreKey> for Vec<u8> {
+    fn from(store_key: StoreKey) -> Self {
+        store_key.0
+    }
+}
+
+impl std::fmt::Display for StoreKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match std::str::from_utf8(&self.0) {
+            Ok(text) if !text.contains(':') && !text.chars().any(char::is_control) => {
+                write!(f, "{}", text)
+            }
+            _ => write!(f, "base64:{}", base64::encode(&self.0)),
+        }
+    }
+}
+
+impl std::str::FromStr for StoreKey {
+    type Err = color_eyre::eyre::ErrReport;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(text) = s.strip_prefix("utf8:") {
+            Ok(Self(text.as_bytes().to_vec()))
+        } else if let Some(hex_key) = s.strip_prefix("hex:") {
+            Ok(Self(hex::decode(hex_key).map_err(|err| {
+                color_eyre::Report::msg(format!("The key is not a valid hex string: {}", err))
+            })?))
+        } else if let Some(base64_key) = s.strip_prefix("base64:") {
+            Ok(Self(base64::decode(base64_key).map_err(|err| {
+                color_eyre::Report::msg(format!("The key is not a valid base64 string: {}", err))
+            })?))
+        } else {
+            Ok(Self(s.as_bytes().to_vec()))
+        }
+    }
+}
+
+impl interactive_clap::ToCli for StoreKey {
+    type CliVariant = StoreKey;
+}
+
+/// Asks whether the contract state should be filtered by a key prefix, and for the prefix
+pub fn input_key_prefix(
+    question: &str,
+    filtered: &str,
+    whole: &str,
+) -> color_eyre::eyre::Result<Option<StoreKey>> {
+    println!();
+    let select_choose_input =
+        dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+            .with_prompt(question)
+            .items(&[filtered, whole])
+            .default(0)
+            .interact_on_opt(&dialoguer::console::Term::stderr())?;
+    match select_choose_input {
+        Some(0) => {
+            let prefix: StoreKey = dialoguer::Input::new()
+                .with_prompt("Enter the key prefix (utf8:<text>, hex:<hex> or base64:<base64>)")
+                .interact_text()?;
+            Ok(Some(prefix))
+        }
+        Some(1) => Ok(None),
+        _ => unreachable!("Error"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_store_key() {
+        assert_eq!(
+            "STATE".parse::<StoreKey>().unwrap(),
+            StoreKey(b"STATE".to_vec())
+        );
+        assert_eq!(
+            "utf8:a:b".parse::<StoreKey>().unwrap(),
+            StoreKey(b"a:b".to_vec())
+        );
+        assert_eq!(
+            "hex:01ab".parse::<StoreKey>().unwrap(),
+            StoreKey(vec![1, 171])
+        );
+        assert_eq!(
+            "base64:/w==".parse::<StoreKey>().unwrap(),
+            StoreKey(vec![255])
+        );
+        assert!("hex:0g".parse::<StoreKey>().is_err());
+        assert!("base64:!".parse::<StoreKey>().is_err());
+    }
+
+    #[test]
+    fn display_store_key() {
+        assert_eq!(StoreKey(b"STATE".to_vec()).to_string(), "STATE");
+        assert_eq!(StoreKey(b"a:b".to_vec()).to_string(), "base64:YTpi");
+        assert_eq!(StoreKey(vec![0]).to_string(), "base64:AA==");
+        assert_eq!(StoreKey(vec![255]).to_string(), "base64:/w==");
+        for key in [StoreKey(b"a:b".to_vec()), StoreKey(vec![0, 255])].iter() {
+            assert_eq!(&key.to_string().parse::<StoreKey>().unwrap(), key);
+        }
+    }
+}